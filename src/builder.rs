@@ -0,0 +1,114 @@
+//! A builder for constructing a [Roll] without remembering the argument order of [`Roll::new()`].
+
+use crate::{Roll, RollError};
+
+/// Builds a [Roll] step by step (e.g. `RollBuilder::new().sides(20).dice(4).modifier(5)`).
+///
+/// By default, a single d6 without a modifier is built.
+///
+/// # Examples
+/// ```
+/// use die_parser::builder::RollBuilder;
+/// use die_parser::Roll;
+///
+/// let roll = RollBuilder::new().sides(20).dice(4).modifier(5).build();
+/// assert_eq!(roll, Ok(Roll::new(20, 4, 5)));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct RollBuilder {
+    number_of_sides: u16,
+    number_of_dice: u16,
+    modifier: i32,
+}
+impl RollBuilder {
+    /// Creates a new [RollBuilder] for a single d6 without a modifier.
+    pub fn new() -> Self {
+        Self {
+            number_of_sides: 6,
+            number_of_dice: 1,
+            modifier: 0,
+        }
+    }
+
+    /// Sets the type of die.
+    pub fn sides(self, number_of_sides: u16) -> Self {
+        Self {
+            number_of_sides,
+            ..self
+        }
+    }
+
+    /// Sets how many dice are to be rolled.
+    pub fn dice(self, number_of_dice: u16) -> Self {
+        Self {
+            number_of_dice,
+            ..self
+        }
+    }
+
+    /// Sets the modifier to be added to the result of the die rolls.
+    pub fn modifier(self, modifier: i32) -> Self {
+        Self { modifier, ..self }
+    }
+
+    /// **Builds the [Roll], checking it the same way [`Roll::parse_roll()`] does.**
+    ///
+    /// * Checks for validity of roll.[^1]
+    ///     * Enforces a limit of 100 dice per roll.
+    ///
+    /// # Examples
+    /// ```
+    /// use die_parser::builder::RollBuilder;
+    /// use die_parser::RollError;
+    ///
+    /// let invalid_roll = RollBuilder::new().dice(0).build();
+    /// assert_eq!(invalid_roll, Err(RollError::NoDiceToRoll));
+    /// ```
+    /// [^1]: Valid die types are: d2, d4, d6, d8, d10, d12, d20, d100
+    pub fn build(self) -> Result<Roll, RollError> {
+        let roll = Roll::new(self.number_of_sides, self.number_of_dice, self.modifier);
+
+        roll.check_roll_validity(100)?;
+        Ok(roll)
+    }
+}
+impl Default for RollBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_build() {
+        let tests = [
+            (RollBuilder::new(), Ok(Roll::new(6, 1, 0))),
+            (
+                RollBuilder::new().sides(20).dice(4).modifier(5),
+                Ok(Roll::new(20, 4, 5)),
+            ),
+            (
+                RollBuilder::new().modifier(-2).sides(8),
+                Ok(Roll::new(8, 1, -2)),
+            ),
+            (RollBuilder::new().dice(0), Err(RollError::NoDiceToRoll)),
+            (
+                RollBuilder::new().dice(101),
+                Err(RollError::DiceExceedLimit),
+            ),
+            (RollBuilder::new().sides(7), Err(RollError::DieTypeInvalid)),
+        ];
+
+        for (builder, expected_output) in tests {
+            assert_eq!(builder.build(), expected_output);
+        }
+    }
+
+    #[test]
+    fn test_default() {
+        assert_eq!(RollBuilder::default(), RollBuilder::new());
+    }
+}
@@ -0,0 +1,162 @@
+use nom::character::complete::{char, digit1};
+use nom::combinator::{map_res, opt, recognize};
+use nom::multi::separated_list1;
+use nom::sequence::{delimited, pair, separated_pair};
+use nom::IResult;
+
+use std::str::FromStr;
+
+use crate::{parse_modifier, parse_numbers, Roll, RollError};
+
+/// A [Roll] that may use custom dice whose faces show arbitrary values.
+#[derive(Debug, PartialEq)]
+pub struct AnyDiceRoll {
+    /// The parsed roll. `number_of_sides` holds the amount of custom faces, if there are any.
+    pub roll: Roll,
+    /// The values shown on the faces of a custom die.
+    pub custom_faces: Option<Vec<i16>>,
+}
+#[cfg(feature = "rand")]
+impl AnyDiceRoll {
+    /// Rolls the dice and adds the modifier, sampling from the custom faces if there are any.
+    pub fn roll_custom<R: rand::Rng + ?Sized>(&self, rng: &mut R) -> i32 {
+        let faces = match &self.custom_faces {
+            Some(faces) => faces,
+            // Dice without custom faces roll with all of their options.
+            None => return self.roll.roll_with_rng(rng),
+        };
+
+        let sum: i32 = (0..self.roll.number_of_dice)
+            .map(|_| faces[rng.gen_range(0..faces.len())] as i32)
+            .sum();
+
+        sum.saturating_add(self.roll.modifier)
+    }
+}
+
+impl Roll {
+    /// **Tries to parse input as AnyDice roll notation (e.g. `2d{1,1,2,2,3,4}+1`).**
+    ///
+    /// * Whitespaces are ignored.
+    /// * Custom dice list the values of their faces in braces.
+    ///     * Any amount of faces is allowed.
+    /// * Dice without custom faces are parsed like [`Roll::parse_roll()`] does.
+    /// * Enforces a limit of 100 dice per roll.
+    ///
+    /// # Examples
+    /// ```
+    /// use die_parser::Roll;
+    ///
+    /// let anydice_roll = Roll::parse_roll_from_anydice_notation("2d{-1, 0, 1}").unwrap();
+    /// assert_eq!(anydice_roll.roll, Roll::new(3, 2, 0));
+    /// assert_eq!(anydice_roll.custom_faces, Some(vec![-1, 0, 1]));
+    /// ```
+    pub fn parse_roll_from_anydice_notation(input: &str) -> Result<AnyDiceRoll, RollError> {
+        // Remove whitespaces.
+        let whitespaceless = input.replace(' ', "");
+
+        // Dice without custom faces are regular rolls.
+        if !whitespaceless.contains('{') {
+            return Ok(AnyDiceRoll {
+                roll: Roll::parse_roll(&whitespaceless)?,
+                custom_faces: None,
+            });
+        }
+
+        // Parse amount of dice and the custom faces.
+        let (remainder, (number_of_dice, faces)) = match parse_custom_dice(&whitespaceless) {
+            Ok(v) => v,
+            Err(_) => return Err(RollError::ParsingError),
+        };
+
+        // Parse the modifier, which has to end the input.
+        let (remainder, modifier) = parse_modifier(remainder)?;
+        if !remainder.is_empty() {
+            return Err(RollError::ParsingError);
+        }
+
+        let number_of_sides = u16::try_from(faces.len()).map_err(|_| RollError::ParsingError)?;
+        let roll = Roll::new(number_of_sides, number_of_dice, modifier);
+
+        // Any amount of faces is fine, so only check the amount of dice.
+        if roll.number_of_dice == 0 {
+            return Err(RollError::NoDiceToRoll);
+        } else if roll.number_of_dice > 100 {
+            return Err(RollError::DiceExceedLimit);
+        }
+
+        Ok(AnyDiceRoll {
+            roll,
+            custom_faces: Some(faces),
+        })
+    }
+}
+
+/// Parse an `i16` with an optional sign from the start of the input string.
+fn parse_face(input: &str) -> IResult<&str, i16> {
+    map_res(recognize(pair(opt(char('-')), digit1)), i16::from_str)(input)
+}
+
+/// Tries to parse amount of dice and their custom faces (e.g. `2d{1,1,2}`).
+fn parse_custom_dice(s: &str) -> IResult<&str, (u16, Vec<i16>)> {
+    let faces = delimited(char('{'), separated_list1(char(','), parse_face), char('}'));
+    separated_pair(parse_numbers, char('d'), faces)(s)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_roll_from_anydice_notation() {
+        let tests = [
+            (
+                "2d{1,1,2,2,3,4}",
+                Ok((Roll::new(6, 2, 0), Some(vec![1, 1, 2, 2, 3, 4]))),
+            ),
+            (
+                "1d{-1, 0, 1} + 2",
+                Ok((Roll::new(3, 1, 2), Some(vec![-1, 0, 1]))),
+            ),
+            ("3d6-1", Ok((Roll::new(6, 3, -1), None))),
+            ("2d{}", Err(RollError::ParsingError)),
+            ("2d{1,2", Err(RollError::ParsingError)),
+            ("0d{1,2}", Err(RollError::NoDiceToRoll)),
+            ("101d{1,2}", Err(RollError::DiceExceedLimit)),
+            ("2d{1,2}+1x", Err(RollError::ParsingError)),
+            ("2d{1,2}!", Err(RollError::ParsingError)),
+            ("2d{1,2}kh1", Err(RollError::ParsingError)),
+        ];
+
+        for (input, expected_output) in tests {
+            let output = Roll::parse_roll_from_anydice_notation(input)
+                .map(|anydice_roll| (anydice_roll.roll, anydice_roll.custom_faces));
+            assert_eq!(output, expected_output);
+        }
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn test_roll_custom() {
+        let mut rng = rand::thread_rng();
+        let anydice_roll = Roll::parse_roll_from_anydice_notation("3d{5,10}+1").unwrap();
+
+        for _ in 0..100 {
+            let result = anydice_roll.roll_custom(&mut rng);
+            assert!([16, 21, 26, 31].contains(&result));
+        }
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn test_roll_custom_without_custom_faces() {
+        let mut rng = rand::thread_rng();
+        let fate_roll = Roll::parse_roll_from_anydice_notation("4dF").unwrap();
+        let kept_roll = Roll::parse_roll_from_anydice_notation("4d6kh1").unwrap();
+
+        for _ in 0..100 {
+            assert!((-4..=4).contains(&fate_roll.roll_custom(&mut rng)));
+            assert!((1..=6).contains(&kept_roll.roll_custom(&mut rng)));
+        }
+    }
+}
@@ -0,0 +1,47 @@
+use crate::{Roll, RollError};
+
+/// Characters Discord uses for markdown formatting.
+const DISCORD_MARKDOWN: [char; 4] = ['`', '*', '_', '~'];
+
+impl Roll {
+    /// **Tries to parse roll notation copied from a Discord message (e.g. `` `2d6+3` `` or `**2d6+3**`).**
+    ///
+    /// * Backticks, asterisks, underscores and tildes are ignored.
+    /// * Checks for validity of roll the same way [`Roll::parse_roll()`] does.
+    ///
+    /// # Examples
+    /// ```
+    /// use die_parser::Roll;
+    ///
+    /// let roll = Roll::parse_roll_from_discord_markdown("**2d6+3**");
+    /// assert_eq!(roll, Ok(Roll::new(6, 2, 3)));
+    /// ```
+    pub fn parse_roll_from_discord_markdown(input: &str) -> Result<Roll, RollError> {
+        let unformatted = input.replace(DISCORD_MARKDOWN, "");
+
+        Roll::parse_roll(&unformatted)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_roll_from_discord_markdown() {
+        let tests = [
+            ("`2d6+3`", Ok(Roll::new(6, 2, 3))),
+            ("```1d20 - 1```", Ok(Roll::new(20, 1, -1))),
+            ("**2d6**+3", Ok(Roll::new(6, 2, 3))),
+            ("__~~4d8~~__", Ok(Roll::new(8, 4, 0))),
+            ("`invalid`", Err(RollError::ParsingError)),
+        ];
+
+        for (input, expected_output) in tests {
+            assert_eq!(
+                Roll::parse_roll_from_discord_markdown(input),
+                expected_output
+            );
+        }
+    }
+}
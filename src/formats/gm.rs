@@ -0,0 +1,52 @@
+use crate::{Roll, RollError};
+
+impl Roll {
+    /// **Tries to parse roll notation written in game master shorthand (e.g. `ATK: 1d20+7`).**
+    ///
+    /// * A leading key (an uppercase word followed by `: `) is silently skipped.
+    /// * Checks for validity of roll the same way [`Roll::parse_roll()`] does.
+    ///
+    /// # Examples
+    /// ```
+    /// use die_parser::Roll;
+    ///
+    /// let roll = Roll::parse_roll_from_gm_notation("DMG: 2d6+4");
+    /// assert_eq!(roll, Ok(Roll::new(6, 2, 4)));
+    /// ```
+    pub fn parse_roll_from_gm_notation(input: &str) -> Result<Roll, RollError> {
+        let input = input.trim_start();
+
+        // Skip the key if there is one.
+        let remainder = match input.split_once(": ") {
+            Some((key, remainder))
+                if !key.is_empty() && key.chars().all(|c| c.is_ascii_uppercase()) =>
+            {
+                remainder
+            }
+            _ => input,
+        };
+
+        Roll::parse_roll(remainder)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_roll_from_gm_notation() {
+        let tests = [
+            ("ATK: 1d20+7", Ok(Roll::new(20, 1, 7))),
+            ("DMG: 2d6 + 4", Ok(Roll::new(6, 2, 4))),
+            ("2d6+4", Ok(Roll::new(6, 2, 4))),
+            ("Atk: 1d20+7", Err(RollError::ParsingError)),
+            ("ATK:1d20+7", Err(RollError::ParsingError)),
+            ("DMG: 2d7", Err(RollError::DieTypeInvalid)),
+        ];
+
+        for (input, expected_output) in tests {
+            assert_eq!(Roll::parse_roll_from_gm_notation(input), expected_output);
+        }
+    }
+}
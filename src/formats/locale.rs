@@ -0,0 +1,104 @@
+use crate::{Roll, RollError, DIE_TYPES};
+
+impl Roll {
+    /// **Tries to parse input as roll notation (e.g. `4d20 + 5`), honoring the numeric separators of a locale.**
+    ///
+    /// * Supported locales are:
+    ///     * `en_US`: No thousands separator.
+    ///     * `de_DE`: `.` as thousands separator (e.g. `1.000d20`).
+    ///         * Separators have to group the digits by three (e.g. not `1.00`).
+    /// * Unsupported locales result in a [`RollError::ParsingError`].
+    /// * Nothing may follow the modifier.
+    /// * Checks for validity of roll the same way [`Roll::parse_roll()`] does.
+    ///
+    /// # Examples
+    /// ```
+    /// use die_parser::{Roll, RollError};
+    ///
+    /// let roll = Roll::parse_roll_with_locale("2d6 + 1.000", "de_DE");
+    /// assert_eq!(roll, Ok(Roll::new(6, 2, 1000)));
+    ///
+    /// let invalid_roll = Roll::parse_roll_with_locale("2d6 + 1.000", "en_US");
+    /// assert_eq!(invalid_roll, Err(RollError::ParsingError));
+    /// ```
+    pub fn parse_roll_with_locale(input: &str, locale: &str) -> Result<Roll, RollError> {
+        // Remove whitespaces.
+        let whitespaceless = input.replace(' ', "");
+
+        let notation = match locale {
+            "en_US" => whitespaceless,
+            "de_DE" => remove_thousands_separators(&whitespaceless)?,
+            _ => return Err(RollError::ParsingError),
+        };
+
+        // Leftover input could be a separator of another locale (e.g. `1.000` read as `1`).
+        let (remainder, result) = Roll::parse_roll_notation(&notation)?;
+        if !remainder.is_empty() {
+            return Err(RollError::ParsingError);
+        }
+
+        result.check_parsed_roll_validity(100, &DIE_TYPES)?;
+        Ok(result)
+    }
+}
+
+/// Removes `.` used as thousands separators from every number of the input.
+///
+/// Returns [`RollError::ParsingError`] if a separator doesn't group the digits by three.
+fn remove_thousands_separators(input: &str) -> Result<String, RollError> {
+    let is_number = |c: char| c.is_ascii_digit() || c == '.';
+
+    let mut result = String::with_capacity(input.len());
+    let mut rest = input;
+    while let Some(start) = rest.find(is_number) {
+        result.push_str(&rest[..start]);
+
+        let number = &rest[start..];
+        let (number, after) =
+            number.split_at(number.find(|c| !is_number(c)).unwrap_or(number.len()));
+
+        // The first group has one to three digits, every following group exactly three.
+        if number.contains('.') {
+            let mut groups = number.split('.');
+            let first_group = groups.next().unwrap_or_default();
+            if !(1..=3).contains(&first_group.len()) || groups.any(|group| group.len() != 3) {
+                return Err(RollError::ParsingError);
+            }
+        }
+
+        result.extend(number.split('.'));
+        rest = after;
+    }
+    result.push_str(rest);
+
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_roll_with_locale() {
+        let tests = [
+            ("4d20+5", "en_US", Ok(Roll::new(20, 4, 5))),
+            ("4d20+5", "de_DE", Ok(Roll::new(20, 4, 5))),
+            ("1.000d20", "en_US", Err(RollError::ParsingError)),
+            ("1.000d20", "de_DE", Err(RollError::DiceExceedLimit)),
+            ("1d20+1.500", "de_DE", Ok(Roll::new(20, 1, 1500))),
+            ("1d20+10.000-1", "de_DE", Ok(Roll::new(20, 1, 9999))),
+            ("2d6+1.000", "en_US", Err(RollError::ParsingError)),
+            ("2d6+1.00", "de_DE", Err(RollError::ParsingError)),
+            ("2d6+1000.000", "de_DE", Err(RollError::ParsingError)),
+            ("2d6+.500", "de_DE", Err(RollError::ParsingError)),
+            ("2d6+1..000", "de_DE", Err(RollError::ParsingError)),
+            ("2d6+1.000abc", "de_DE", Err(RollError::ParsingError)),
+            ("4dF+1", "de_DE", Ok(Roll::new_fate(4, 1))),
+            ("4d20+5", "xx_XX", Err(RollError::ParsingError)),
+        ];
+
+        for (input, locale, expected_output) in tests {
+            assert_eq!(Roll::parse_roll_with_locale(input, locale), expected_output);
+        }
+    }
+}
@@ -0,0 +1,13 @@
+//! Adapters for die roll notation as it is written by other tools and platforms.
+
+mod anydice;
+mod discord;
+mod gm;
+mod locale;
+#[cfg(feature = "python-compat")]
+mod python;
+mod slack;
+#[cfg(feature = "speech")]
+mod speech;
+
+pub use anydice::AnyDiceRoll;
@@ -0,0 +1,223 @@
+use nom::bytes::complete::tag;
+use nom::character::complete::char;
+use nom::combinator::{map, opt};
+use nom::sequence::{delimited, preceded, tuple};
+use nom::{branch, IResult};
+
+use crate::{
+    parse_modifier, parse_numbers, parse_simple_roll, DieKind, KeepMode, Roll, RollError, DIE_TYPES,
+};
+
+/// Amount and type of die, the face the dice explode on and which of them are kept.
+type PythonDice = ((u16, u16), Option<u16>, Option<KeepMode>);
+
+impl Roll {
+    /// **Converts the roll into the notation used by Python's `d20` library (e.g. `(4d20)+5`).**
+    ///
+    /// * The dice are grouped in parentheses.
+    /// * Exploding dice are written as exploding on their highest face (e.g. `(2d6e6)`).
+    /// * Kept dice are written as `kh` or `kl`, dropped dice as `pl` or `ph` (e.g. `(4d6kh3)`).
+    /// * Fate dice are written as three-sided dice, with their offset moved into the modifier (e.g. `(4d3)-8`).
+    /// * The modifier always carries an explicit sign and is omitted if it is `0`.
+    ///
+    /// # Examples
+    /// ```
+    /// use die_parser::{KeepMode, Roll};
+    ///
+    /// assert_eq!(Roll::new(20, 4, 5).to_python_dice_notation(), "(4d20)+5");
+    /// assert_eq!(Roll::new(6, 2, 0).to_python_dice_notation(), "(2d6)");
+    /// assert_eq!(
+    ///     Roll::new(6, 4, 0).keep(KeepMode::DropLowest(1)).to_python_dice_notation(),
+    ///     "(4d6pl1)"
+    /// );
+    /// assert_eq!(Roll::new_fate(4, 1).to_python_dice_notation(), "(4d3)-7");
+    /// ```
+    pub fn to_python_dice_notation(&self) -> String {
+        let mut dice = format!("{}d{}", self.number_of_dice, self.number_of_sides);
+
+        if self.exploding {
+            dice.push_str(&format!("e{}", self.number_of_sides));
+        }
+
+        match self.keep_mode {
+            Some(KeepMode::Highest(n)) => dice.push_str(&format!("kh{n}")),
+            Some(KeepMode::Lowest(n)) => dice.push_str(&format!("kl{n}")),
+            Some(KeepMode::DropLowest(n)) => dice.push_str(&format!("pl{n}")),
+            Some(KeepMode::DropHighest(n)) => dice.push_str(&format!("ph{n}")),
+            None => (),
+        }
+
+        // The library has no Fate dice, so every kept die gets its offset through the modifier.
+        let (kept_dice, _) = self.kept_dice();
+        let offset = i32::from(kept_dice).saturating_mul(self.die_kind.face_offset());
+
+        match self.modifier.saturating_add(offset) {
+            0 => format!("({dice})"),
+            modifier => format!("({dice}){modifier:+}"),
+        }
+    }
+
+    /// **Tries to parse input as notated by Python's `d20` library (e.g. `(4d20)+5`).**
+    ///
+    /// * Whitespaces are ignored.
+    /// * Parentheses around the dice are optional.
+    /// * Reads the exploding, keep and drop suffixes written by [`Roll::to_python_dice_notation()`].
+    /// * Three-sided dice are read as Fate dice, with their offset taken back out of the modifier.
+    /// * Checks for validity of roll the same way [`Roll::parse_roll()`] does.
+    ///
+    /// # Examples
+    /// ```
+    /// use die_parser::Roll;
+    ///
+    /// let roll = Roll::parse_roll_from_python_notation("(3d10)-5");
+    /// assert_eq!(roll, Ok(Roll::new(10, 3, -5)));
+    ///
+    /// let roll = Roll::parse_roll_from_python_notation("(4d3)-7");
+    /// assert_eq!(roll, Ok(Roll::new_fate(4, 1)));
+    /// ```
+    pub fn parse_roll_from_python_notation(input: &str) -> Result<Roll, RollError> {
+        // Remove whitespaces.
+        let whitespaceless = input.replace(' ', "");
+
+        // Parse type of die, amount of dice and their suffixes, with or without grouping.
+        let (remainder, ((number_of_dice, number_of_sides), exploding_face, keep_mode)) =
+            match parse_grouped_roll(&whitespaceless) {
+                Ok(v) => v,
+                Err(_) => return Err(RollError::ParsingError),
+            };
+
+        // Only dice exploding on their highest face can be expressed as a `Roll`.
+        if exploding_face.is_some_and(|face| face != number_of_sides) {
+            return Err(RollError::ParsingError);
+        }
+
+        // Parse the modifier
+        let (_, modifier) = parse_modifier(remainder)?;
+
+        let mut result = Roll {
+            number_of_sides,
+            number_of_dice,
+            modifier,
+            exploding: exploding_face.is_some(),
+            keep_mode,
+            die_kind: DieKind::Standard,
+        };
+
+        // Three-sided dice are how Fate dice get exported, so their offset is taken back out.
+        if number_of_sides == 3 {
+            result.die_kind = DieKind::Fate;
+            let (kept_dice, _) = result.kept_dice();
+            result.modifier = i32::from(kept_dice)
+                .checked_mul(DieKind::Fate.face_offset())
+                .and_then(|offset| modifier.checked_sub(offset))
+                .ok_or(RollError::ModifierOverflow)?;
+        }
+
+        result.check_parsed_roll_validity(100, &DIE_TYPES)?;
+        Ok(result)
+    }
+}
+
+/// Tries to parse a notated die roll that may be wrapped in parentheses (e.g. `(4d20)`).
+fn parse_grouped_roll(s: &str) -> IResult<&str, PythonDice> {
+    branch::alt((
+        delimited(char('('), parse_python_dice, char(')')),
+        parse_python_dice,
+    ))(s)
+}
+
+/// Tries to parse notated dice with their optional exploding and keep suffixes (e.g. `4d6e6kh3`).
+fn parse_python_dice(s: &str) -> IResult<&str, PythonDice> {
+    tuple((
+        parse_simple_roll,
+        opt(preceded(char('e'), parse_numbers)),
+        opt(parse_python_keep_mode),
+    ))(s)
+}
+
+/// Tries to parse the keep or drop suffix of notated dice (e.g. `kh3` or `pl1`).
+fn parse_python_keep_mode(s: &str) -> IResult<&str, KeepMode> {
+    branch::alt((
+        map(preceded(tag("kh"), parse_numbers), KeepMode::Highest),
+        map(preceded(tag("kl"), parse_numbers), KeepMode::Lowest),
+        map(preceded(tag("pl"), parse_numbers), KeepMode::DropLowest),
+        map(preceded(tag("ph"), parse_numbers), KeepMode::DropHighest),
+    ))(s)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_python_notation_round_trip() {
+        let tests = [
+            Roll::new(20, 4, 5),
+            Roll::new(6, 2, 0),
+            Roll::new(100, 1, -10),
+            Roll::new(6, 4, 0).keep(KeepMode::Highest(3)),
+            Roll::new(6, 2, 0).exploding(),
+            Roll::new_fate(4, 1),
+        ];
+
+        for roll in tests {
+            let notation = roll.to_python_dice_notation();
+            assert_eq!(Roll::parse_roll_from_python_notation(&notation), Ok(roll));
+        }
+    }
+
+    #[test]
+    fn test_to_python_dice_notation() {
+        let tests = [
+            (Roll::new(6, 4, 0).keep(KeepMode::Highest(3)), "(4d6kh3)"),
+            (Roll::new(20, 2, 1).keep(KeepMode::Lowest(1)), "(2d20kl1)+1"),
+            (
+                Roll::new(8, 6, 0).keep(KeepMode::DropHighest(2)),
+                "(6d8ph2)",
+            ),
+            (Roll::new(6, 3, -1).exploding(), "(3d6e6)-1"),
+            (Roll::new_fate(4, 0), "(4d3)-8"),
+            (Roll::new_fate(4, 8), "(4d3)"),
+            (
+                Roll::new_fate(4, 0).keep(KeepMode::Highest(2)),
+                "(4d3kh2)-4",
+            ),
+        ];
+
+        for (roll, expected_output) in tests {
+            assert_eq!(roll.to_python_dice_notation(), expected_output);
+        }
+    }
+
+    #[test]
+    fn test_parse_roll_from_python_notation() {
+        let tests = [
+            ("(2d6)+3", Ok(Roll::new(6, 2, 3))),
+            ("2d6 + 3", Ok(Roll::new(6, 2, 3))),
+            ("(2d6+3", Err(RollError::ParsingError)),
+            ("(2d7)", Err(RollError::DieTypeInvalid)),
+            (
+                "(4d6kh3)",
+                Ok(Roll::new(6, 4, 0).keep(KeepMode::Highest(3))),
+            ),
+            (
+                "(6d8ph2)-1",
+                Ok(Roll::new(8, 6, -1).keep(KeepMode::DropHighest(2))),
+            ),
+            ("(3d6e6)-1", Ok(Roll::new(6, 3, -1).exploding())),
+            ("(3d6e5)", Err(RollError::ParsingError)),
+            ("(4d3)", Ok(Roll::new_fate(4, 8))),
+            (
+                "(4d3kh2)-4",
+                Ok(Roll::new_fate(4, 0).keep(KeepMode::Highest(2))),
+            ),
+        ];
+
+        for (input, expected_output) in tests {
+            assert_eq!(
+                Roll::parse_roll_from_python_notation(input),
+                expected_output
+            );
+        }
+    }
+}
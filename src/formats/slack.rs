@@ -0,0 +1,60 @@
+use crate::{Roll, RollError};
+
+impl Roll {
+    /// **Tries to parse input as emoji-encoded roll notation used by Slack bots (e.g. `:3d8:`).**
+    ///
+    /// * The dice have to be wrapped in colons, a modifier may follow them (e.g. `:3d8:+5`).
+    /// * A missing amount of dice is read as a single die (e.g. `:d6:`).
+    /// * Checks for validity of roll the same way [`Roll::parse_roll()`] does.
+    ///
+    /// # Examples
+    /// ```
+    /// use die_parser::Roll;
+    /// use die_parser::RollError;
+    ///
+    /// let roll = Roll::parse_roll_from_slack_emoji(":d6:");
+    /// assert_eq!(roll, Ok(Roll::new(6, 1, 0)));
+    ///
+    /// let invalid_roll = Roll::parse_roll_from_slack_emoji(":3d8:+5:");
+    /// assert_eq!(invalid_roll, Err(RollError::ParsingError));
+    /// ```
+    pub fn parse_roll_from_slack_emoji(input: &str) -> Result<Roll, RollError> {
+        // Split the emoji from the modifier.
+        let (dice, modifier) = match input.trim().strip_prefix(':') {
+            Some(emoji) => emoji.split_once(':').ok_or(RollError::ParsingError)?,
+            None => return Err(RollError::ParsingError),
+        };
+
+        // Only the dice may be wrapped in colons.
+        if modifier.contains(':') {
+            return Err(RollError::ParsingError);
+        }
+
+        // Emojis like `:d6:` imply a single die.
+        let count = if dice.starts_with('d') { "1" } else { "" };
+
+        Roll::parse_roll(&format!("{count}{dice}{modifier}"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_roll_from_slack_emoji() {
+        let tests = [
+            (":d6:", Ok(Roll::new(6, 1, 0))),
+            (":3d8:", Ok(Roll::new(8, 3, 0))),
+            (":3d8: + 5", Ok(Roll::new(8, 3, 5))),
+            ("3d8", Err(RollError::ParsingError)),
+            (":3d8", Err(RollError::ParsingError)),
+            (":3d8:+5:", Err(RollError::ParsingError)),
+            (":3d7:", Err(RollError::DieTypeInvalid)),
+        ];
+
+        for (input, expected_output) in tests {
+            assert_eq!(Roll::parse_roll_from_slack_emoji(input), expected_output);
+        }
+    }
+}
@@ -0,0 +1,179 @@
+use crate::{Roll, RollError};
+
+/// Number words that stand on their own, indexed by their value.
+const UNITS: [&str; 20] = [
+    "zero",
+    "one",
+    "two",
+    "three",
+    "four",
+    "five",
+    "six",
+    "seven",
+    "eight",
+    "nine",
+    "ten",
+    "eleven",
+    "twelve",
+    "thirteen",
+    "fourteen",
+    "fifteen",
+    "sixteen",
+    "seventeen",
+    "eighteen",
+    "nineteen",
+];
+
+/// Number words for multiples of ten, indexed by their value divided by ten.
+const TENS: [&str; 10] = [
+    "", "", "twenty", "thirty", "forty", "fifty", "sixty", "seventy", "eighty", "ninety",
+];
+
+impl Roll {
+    /// **Tries to parse spoken roll notation as produced by speech-to-text (e.g. `two dee six plus three`).**
+    ///
+    /// * Numbers may be written as words (up to `nine hundred ninety nine`) or digits.
+    /// * `dee` (or `d`) separates the amount of dice from the type of die.
+    /// * `plus` and `minus` introduce the modifier.
+    /// * Checks for validity of roll the same way [`Roll::parse_roll()`] does.
+    ///
+    /// # Examples
+    /// ```
+    /// use die_parser::Roll;
+    ///
+    /// let roll = Roll::parse_roll_from_speech("two dee six plus three");
+    /// assert_eq!(roll, Ok(Roll::new(6, 2, 3)));
+    ///
+    /// let roll = Roll::parse_roll_from_speech("one d one hundred minus twenty-five");
+    /// assert_eq!(roll, Ok(Roll::new(100, 1, -25)));
+    /// ```
+    pub fn parse_roll_from_speech(input: &str) -> Result<Roll, RollError> {
+        let lowercase = input.to_lowercase();
+        let words = split_words(&lowercase);
+
+        // Translate every word into regular roll notation.
+        let mut notation = String::new();
+        let mut remainder = &words[..];
+        while let Some(word) = remainder.first() {
+            if let Some((number, length)) = parse_number_words(remainder) {
+                notation.push_str(&number.to_string());
+                remainder = &remainder[length..];
+                continue;
+            }
+
+            match *word {
+                "dee" | "d" => notation.push('d'),
+                "plus" => notation.push('+'),
+                "minus" => notation.push('-'),
+                _ => return Err(RollError::ParsingError),
+            }
+            remainder = &remainder[1..];
+        }
+
+        Roll::parse_roll(&notation)
+    }
+}
+
+/// Splits spoken input into single words, separating a `d` from any digits following it (e.g. `d20`).
+fn split_words(input: &str) -> Vec<&str> {
+    let mut words = Vec::new();
+
+    for word in input.split(|c: char| c.is_whitespace() || c == '-') {
+        match word.strip_prefix('d') {
+            Some(digits) if !digits.is_empty() && digits.chars().all(|c| c.is_ascii_digit()) => {
+                words.push("d");
+                words.push(digits);
+            }
+            _ if !word.is_empty() => words.push(word),
+            _ => (),
+        }
+    }
+
+    words
+}
+
+/// Tries to read a number from the start of the given words.
+///
+/// Returns the number and how many words it spans.
+fn parse_number_words(words: &[&str]) -> Option<(u32, usize)> {
+    // Numbers written as digits.
+    if let Some(number) = words.first().and_then(|word| word.parse().ok()) {
+        return Some((number, 1));
+    }
+
+    let position_of = |list: &[&str], index: usize| {
+        let word = words.get(index)?;
+        list.iter()
+            .position(|entry| !entry.is_empty() && entry == word)
+    };
+
+    let mut value = 0;
+    let mut length = 0;
+
+    // Hundreds (e.g. `one hundred`).
+    if let Some(units) = position_of(&UNITS, 0) {
+        if words.get(1) == Some(&"hundred") {
+            value = units as u32 * 100;
+            length = 2;
+        }
+    }
+
+    // Tens (e.g. `twenty`).
+    if let Some(tens) = position_of(&TENS, length) {
+        value += tens as u32 * 10;
+        length += 1;
+
+        // Units following tens have to be single digits (e.g. `twenty one`).
+        if let Some(units) = position_of(&UNITS[1..10], length) {
+            value += units as u32 + 1;
+            length += 1;
+        }
+    } else if let Some(units) = position_of(&UNITS, length) {
+        value += units as u32;
+        length += 1;
+    }
+
+    match length {
+        0 => None,
+        _ => Some((value, length)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_number_words() {
+        let tests = [
+            (vec!["seven"], Some((7, 1))),
+            (vec!["nineteen", "dee"], Some((19, 1))),
+            (vec!["forty", "two"], Some((42, 2))),
+            (vec!["one", "hundred"], Some((100, 2))),
+            (vec!["three", "hundred", "twelve"], Some((312, 3))),
+            (vec!["twenty", "twelve"], Some((20, 1))),
+            (vec!["15"], Some((15, 1))),
+            (vec!["dee"], None),
+        ];
+
+        for (input, expected_output) in tests {
+            assert_eq!(parse_number_words(&input), expected_output);
+        }
+    }
+
+    #[test]
+    fn test_parse_roll_from_speech() {
+        let tests = [
+            ("two dee six plus three", Ok(Roll::new(6, 2, 3))),
+            ("Four Dee Twenty Minus Five", Ok(Roll::new(20, 4, -5))),
+            ("ninety-nine d10", Ok(Roll::new(10, 99, 0))),
+            ("one dee one hundred", Ok(Roll::new(100, 1, 0))),
+            ("two dee seven", Err(RollError::DieTypeInvalid)),
+            ("two dice of six", Err(RollError::ParsingError)),
+        ];
+
+        for (input, expected_output) in tests {
+            assert_eq!(Roll::parse_roll_from_speech(input), expected_output);
+        }
+    }
+}
@@ -24,16 +24,31 @@
 //! **Try [Roll::parse_roll()]!**
 
 use nom::bytes::complete::tag;
-use nom::character::complete::{char, digit1};
-use nom::combinator::{map, map_res};
-use nom::sequence::separated_pair;
+use nom::character::complete::{char, digit1, one_of};
+use nom::combinator::{map, map_res, opt};
+use nom::sequence::{preceded, separated_pair};
 use nom::{branch, IResult};
 use std::fmt;
+use std::ops::Add;
 
 use std::str::FromStr;
 
+pub mod builder;
+pub mod formats;
+mod probability;
+#[cfg(feature = "rand")]
+mod rolling;
+pub mod systems;
+
+/// The types of die that are valid by default.
+const DIE_TYPES: [u16; 8] = [2, 4, 6, 8, 10, 12, 20, 100];
+
+/// The most times a single exploding die is rolled again.
+const MAX_EXPLOSIONS: u16 = 100;
+
 /// Holds information about a die roll.
-#[derive(Debug, PartialEq)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Roll {
     /// The type of die.
     pub number_of_sides: u16,
@@ -41,72 +56,408 @@ pub struct Roll {
     pub number_of_dice: u16,
     /// A modifier to be added to the result of the die rolls.
     pub modifier: i32,
+    /// Whether dice showing their highest face are rolled again and added (e.g. `2d6!`).
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub exploding: bool,
+    /// Which of the rolled dice count towards the result (e.g. `4d6kh3`), all of them if `None`.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub keep_mode: Option<KeepMode>,
+    /// How the faces of the dice are read (e.g. Fate dice in `4dF`).
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub die_kind: DieKind,
 }
 impl Roll {
     /// A convenience function that allows you to manually create a new [Roll].
+    ///
+    /// The dice are not exploding and all of them are kept, see [`Roll::exploding()`] and
+    /// [`Roll::keep()`] to change that.
     pub fn new(number_of_sides: u16, number_of_dice: u16, modifier: i32) -> Self {
         Self {
             number_of_sides,
             number_of_dice,
             modifier,
+            exploding: false,
+            keep_mode: None,
+            die_kind: DieKind::Standard,
+        }
+    }
+
+    /// Creates a new [Roll] of Fate dice, each showing -1, 0 or +1 (e.g. `4dF+2`).
+    ///
+    /// # Example
+    /// ```
+    /// use die_parser::Roll;
+    ///
+    /// let roll = Roll::new_fate(4, 2);
+    /// assert_eq!(Roll::parse_roll("4dF+2"), Ok(roll));
+    /// assert_eq!((roll.min_result(), roll.max_result()), (-2, 6));
+    /// ```
+    pub fn new_fate(number_of_dice: u16, modifier: i32) -> Self {
+        Self {
+            die_kind: DieKind::Fate,
+            ..Roll::new(3, number_of_dice, modifier)
+        }
+    }
+
+    /// Creates a new [Roll] whose dice explode.
+    ///
+    /// # Example
+    /// ```
+    /// use die_parser::Roll;
+    ///
+    /// let roll = Roll::new(6, 2, 0).exploding();
+    /// assert_eq!(Roll::parse_roll("2d6!"), Ok(roll));
+    /// ```
+    pub fn exploding(&self) -> Roll {
+        Roll {
+            exploding: true,
+            ..*self
+        }
+    }
+
+    /// Creates a new [Roll] that only keeps some of the rolled dice.
+    ///
+    /// # Example
+    /// ```
+    /// use die_parser::{KeepMode, Roll};
+    ///
+    /// let roll = Roll::new(6, 4, 0).keep(KeepMode::Highest(3));
+    /// assert_eq!(Roll::parse_roll("4d6kh3"), Ok(roll));
+    /// ```
+    pub fn keep(&self, keep_mode: KeepMode) -> Roll {
+        Roll {
+            keep_mode: Some(keep_mode),
+            ..*self
+        }
+    }
+
+    /// Picks the results of the dice that count towards the total, as set by `keep_mode`.
+    ///
+    /// The kept results are sorted in ascending order.
+    ///
+    /// # Example
+    /// ```
+    /// use die_parser::{KeepMode, Roll};
+    ///
+    /// let roll = Roll::new(6, 4, 0).keep(KeepMode::Highest(3));
+    /// assert_eq!(roll.kept_results(&[3, 1, 6, 4]), vec![3, 4, 6]);
+    /// ```
+    pub fn kept_results(&self, results: &[u16]) -> Vec<u16> {
+        let mut results = results.to_vec();
+        results.sort_unstable();
+
+        match self.keep_mode {
+            Some(KeepMode::Highest(n)) => results.split_off(results.len().saturating_sub(n.into())),
+            Some(KeepMode::Lowest(n)) => {
+                results.truncate(n.into());
+                results
+            }
+            Some(KeepMode::DropLowest(n)) => results.split_off(results.len().min(n.into())),
+            Some(KeepMode::DropHighest(n)) => {
+                results.truncate(results.len().saturating_sub(n.into()));
+                results
+            }
+            None => results,
+        }
+    }
+
+    /// Creates a new [Roll] with `number_of_dice` and `number_of_sides` swapped.
+    ///
+    /// **This is only meant for debugging and testing purposes.**
+    /// `1d20` and `20d1` are very different rolls, so the result is almost certainly invalid.
+    ///
+    /// * All other options of the roll are kept as they are.
+    ///
+    /// # Example
+    /// ```
+    /// use die_parser::Roll;
+    ///
+    /// let roll = Roll::new(20, 1, 5);
+    /// assert_eq!(roll.negate_dice_order(), Roll::new(1, 20, 5));
+    /// ```
+    pub fn negate_dice_order(&self) -> Roll {
+        Roll {
+            number_of_dice: self.number_of_sides,
+            number_of_sides: self.number_of_dice,
+            ..*self
+        }
+    }
+
+    /// Creates a new [Roll] with `n` additional dice.
+    ///
+    /// The amount of dice saturates at `u16::MAX`.
+    ///
+    /// # Example
+    /// ```
+    /// use die_parser::Roll;
+    ///
+    /// let roll = Roll::new(6, 2, 3);
+    /// assert_eq!(roll.add_dice(2), Ok(Roll::new(6, 4, 3)));
+    /// ```
+    pub fn add_dice(&self, n: u16) -> Result<Roll, RollError> {
+        let number_of_dice = self.number_of_dice.saturating_add(n);
+
+        Ok(Roll {
+            number_of_dice,
+            ..*self
+        })
+    }
+
+    /// Creates a new [Roll] with `n` dice less.
+    ///
+    /// Returns [`RollError::NoDiceToRoll`] if no dice would be left.
+    ///
+    /// # Example
+    /// ```
+    /// use die_parser::{Roll, RollError};
+    ///
+    /// let roll = Roll::new(6, 2, 3);
+    /// assert_eq!(roll.remove_dice(1), Ok(Roll::new(6, 1, 3)));
+    /// assert_eq!(roll.remove_dice(2), Err(RollError::NoDiceToRoll));
+    /// ```
+    pub fn remove_dice(&self, n: u16) -> Result<Roll, RollError> {
+        match self.number_of_dice.saturating_sub(n) {
+            0 => Err(RollError::NoDiceToRoll),
+            number_of_dice => Ok(Roll {
+                number_of_dice,
+                ..*self
+            }),
         }
     }
 
+    /// Creates a new [Roll] with `n` added to the modifier.
+    ///
+    /// Returns [`RollError::ModifierOverflow`] if the modifier would exceed the range of an `i32`.
+    ///
+    /// # Example
+    /// ```
+    /// use die_parser::{Roll, RollError};
+    ///
+    /// let roll = Roll::new(6, 2, 3);
+    /// assert_eq!(roll.add_modifier(-5), Ok(Roll::new(6, 2, -2)));
+    /// assert_eq!(roll.add_modifier(i32::MAX), Err(RollError::ModifierOverflow));
+    /// ```
+    pub fn add_modifier(&self, n: i32) -> Result<Roll, RollError> {
+        match self.modifier.checked_add(n) {
+            Some(modifier) => Ok(Roll { modifier, ..*self }),
+            None => Err(RollError::ModifierOverflow),
+        }
+    }
+
+    /// Converts the roll into the shortest notation that still describes it (e.g. `d%` for `1d100`).
+    ///
+    /// * The amount of dice is omitted if it is `1`.
+    /// * The modifier is omitted if it is `0`.
+    /// * `d100` is written as `d%`.
+    ///
+    /// # Example
+    /// ```
+    /// use die_parser::Roll;
+    ///
+    /// assert_eq!(Roll::new(6, 1, 0).to_succinct_string(), "d6");
+    /// assert_eq!(Roll::new(100, 1, 0).to_succinct_string(), "d%");
+    /// assert_eq!(Roll::new(8, 2, -1).to_succinct_string(), "2d8-1");
+    /// ```
+    pub fn to_succinct_string(&self) -> String {
+        let mut succinct = String::new();
+
+        if self.number_of_dice != 1 {
+            succinct.push_str(&self.number_of_dice.to_string());
+        }
+
+        match (self.die_kind, self.number_of_sides) {
+            (DieKind::Fate, _) => succinct.push_str("dF"),
+            (_, 100) => succinct.push_str("d%"),
+            (_, sides) => succinct.push_str(&format!("d{sides}")),
+        }
+
+        if self.exploding {
+            succinct.push('!');
+        }
+
+        if let Some(keep_mode) = self.keep_mode {
+            succinct.push_str(&keep_mode.to_string());
+        }
+
+        if self.modifier != 0 {
+            succinct.push_str(&format!("{:+}", self.modifier));
+        }
+
+        succinct
+    }
+
+    /// Converts the roll into the notation [`Roll::parse_roll()`] reads (e.g. `3d10-5`).
+    ///
+    /// * The modifier is omitted if it is `0`.
+    /// * Parsing the notation again results in the same roll.
+    ///
+    /// # Example
+    /// ```
+    /// use die_parser::Roll;
+    ///
+    /// let roll = Roll::new(10, 3, -5);
+    /// assert_eq!(roll.to_notation(), "3d10-5");
+    /// assert_eq!(Roll::parse_roll(&roll.to_notation()), Ok(roll));
+    /// ```
+    pub fn to_notation(&self) -> String {
+        let mut notation = match self.die_kind {
+            DieKind::Standard => format!("{}d{}", self.number_of_dice, self.number_of_sides),
+            DieKind::Fate => format!("{}dF", self.number_of_dice),
+        };
+
+        if self.exploding {
+            notation.push('!');
+        }
+
+        if let Some(keep_mode) = self.keep_mode {
+            notation.push_str(&keep_mode.to_string());
+        }
+
+        if self.modifier != 0 {
+            notation.push_str(&format!("{:+}", self.modifier));
+        }
+
+        notation
+    }
+
     /// Parses a given input string with no regard to validity.
     fn parse_modified_roll(input: &str) -> Result<Roll, RollError> {
         // Remove whitespaces.
         let whitespaceless = input.replace(' ', "");
 
+        let (_, roll) = Roll::parse_roll_notation(&whitespaceless)?;
+        Ok(roll)
+    }
+
+    /// Parses a given input string without whitespaces with no regard to validity, returning the unparsed remainder.
+    fn parse_roll_notation(s: &str) -> Result<(&str, Roll), RollError> {
         // Parse type of die and amount of dice.
-        let (remainder, (number_of_dice, number_of_sides)) =
-            match parse_simple_roll(&whitespaceless) {
-                Ok(v) => v,
-                Err(_) => return Err(RollError::ParsingError),
-            };
+        let (remainder, ((number_of_dice, number_of_sides), die_kind)) = match parse_dice(s) {
+            Ok(v) => v,
+            Err(_) => return Err(RollError::ParsingError),
+        };
 
-        // Parse the modifier
-        let (_, modifier) = match parse_modifier(remainder) {
+        // Check if the dice explode.
+        let (remainder, exploding) = match remainder.strip_prefix('!') {
+            Some(remainder) => (remainder, true),
+            None => (remainder, false),
+        };
+
+        // Check which dice are kept.
+        let (remainder, keep_mode) = match parse_keep_mode(remainder) {
             Ok(v) => v,
             Err(_) => return Err(RollError::ParsingError),
         };
 
+        // Only one way of keeping dice may be given (e.g. not `4d6kh3dl1`).
+        if let Ok((_, Some(_))) = parse_keep_mode(remainder) {
+            return Err(RollError::ConflictingOptions);
+        }
+
+        // Parse the modifier
+        let (remainder, modifier) = parse_modifier(remainder)?;
+
+        // Options in the wrong place would otherwise be silently ignored (e.g. `4d6kh3!`).
+        if remainder.starts_with(['!', 'k', 'd']) {
+            return Err(RollError::ParsingError);
+        }
+
         // Success!
-        Ok(Roll {
+        let roll = Roll {
             number_of_dice,
             number_of_sides,
             modifier,
-        })
+            exploding,
+            keep_mode,
+            die_kind,
+        };
+        Ok((remainder, roll))
     }
 
     /// Checks if a give roll is using a valid type of die and amount of dice.
     fn check_roll_validity(&self, max_dice: u16) -> Result<(), RollError> {
+        self.check_roll_validity_with_die_types(max_dice, &DIE_TYPES)
+    }
+
+    /// Checks if a give roll is using one of the given types of die and a valid amount of dice.
+    fn check_roll_validity_with_die_types(
+        &self,
+        max_dice: u16,
+        die_types: &[u16],
+    ) -> Result<(), RollError> {
+        first_error(self.roll_validity_errors(max_dice, die_types, false))
+    }
+
+    /// Checks a roll parsed from roll notation like [`Roll::check_roll_validity_with_die_types()`] does,
+    /// but accepts Fate dice as well.
+    fn check_parsed_roll_validity(
+        &self,
+        max_dice: u16,
+        die_types: &[u16],
+    ) -> Result<(), RollError> {
+        first_error(self.roll_validity_errors(max_dice, die_types, true))
+    }
+
+    /// Collects every reason why a given roll is not using one of the given types of die and a valid amount of dice.
+    ///
+    /// Fate dice are only valid if `fate_allowed` is set, regardless of the given types of die.
+    fn roll_validity_errors(
+        &self,
+        max_dice: u16,
+        die_types: &[u16],
+        fate_allowed: bool,
+    ) -> Vec<RollError> {
+        let mut errors = Vec::new();
+
         // Check for die type.
-        match self.number_of_sides {
-            2 => (),
-            4 => (),
-            6 => (),
-            8 => (),
-            10 => (),
-            12 => (),
-            20 => (),
-            100 => (),
-            _ => return Err(RollError::DieTypeInvalid),
+        let valid_die_type = match self.die_kind {
+            DieKind::Standard => die_types.contains(&self.number_of_sides),
+            DieKind::Fate => fate_allowed,
+        };
+        if !valid_die_type {
+            errors.push(RollError::DieTypeInvalid);
         }
 
         // Check for amount of dice. If max_dice == 0 ~> no limit.
-        if self.number_of_dice > max_dice && !max_dice != 0 {
-            return Err(RollError::DiceExceedLimit);
+        if max_dice != 0 && self.number_of_dice > max_dice {
+            errors.push(RollError::DiceExceedLimit);
         } else if self.number_of_dice == 0 {
-            return Err(RollError::NoDiceToRoll);
+            // Parsing reads `0d20` just fine, so rolls without dice are only caught here.
+            errors.push(RollError::NoDiceToRoll);
         }
 
-        // Checks passed.
-        Ok(())
+        // Check for amount of kept or dropped dice, which has to leave at least one die out.
+        if let Some(
+            KeepMode::Highest(n)
+            | KeepMode::Lowest(n)
+            | KeepMode::DropLowest(n)
+            | KeepMode::DropHighest(n),
+        ) = self.keep_mode
+        {
+            if n == 0 || n >= self.number_of_dice {
+                errors.push(RollError::KeepCountInvalid);
+            }
+        }
+
+        // Fate dice have no highest face that could explode.
+        if self.die_kind == DieKind::Fate && self.exploding {
+            errors.push(RollError::ConflictingOptions);
+        }
+
+        errors
     }
 
     /// **Tries to parse input as roll notation (e.g. `4d20 + 5`).**
     ///
     /// * Whitespaces are ignored.
+    /// * The die separator may be uppercase (e.g. `4D20`).
+    /// * A missing amount of dice is read as a single die (e.g. `d20`).
+    /// * A `!` after the type of die makes the dice explode (e.g. `2d6!`).
+    /// * Fate dice are written as `dF` (e.g. `4dF+2`).
+    /// * `d%` is read as `d100` (e.g. `2d%` is read as `2d100`).
+    /// * Modifiers may be chained and are added up (e.g. `1d6+10-4` has a modifier of 6).
+    /// * Only some dice may be kept, either the highest (e.g. `4d6kh3`) or the lowest (e.g. `2d20kl1`).
+    ///     * Alternatively, the lowest (e.g. `4d6dl1`) or highest (e.g. `6d8dh2`) dice may be dropped.
     /// * Checks for validity of roll.[^1]
     ///     * Enforces a limit of 100 dice per roll.[^2]
     ///
@@ -127,7 +478,7 @@ impl Roll {
     pub fn parse_roll(input: &str) -> Result<Roll, RollError> {
         let result = Roll::parse_modified_roll(input)?;
 
-        match result.check_roll_validity(100) {
+        match result.check_parsed_roll_validity(100, &DIE_TYPES) {
             Ok(()) => Ok(result),
             Err(e) => Err(e),
         }
@@ -156,15 +507,288 @@ impl Roll {
         let result = Roll::parse_modified_roll(input)?;
 
         // Check if the roll is valid using the users max_dice value.
-        match result.check_roll_validity(max_dice) {
+        match result.check_parsed_roll_validity(max_dice, &DIE_TYPES) {
             Ok(()) => Ok(result),
             Err(e) => Err(e),
         }
     }
+
+    /// **Tries to parse input as roll notation (e.g. `1d3 + 5`), allowing any type of die.**
+    ///
+    /// * Behaves like [`Roll::parse_roll()`], but accepts every die with at least 2 sides.
+    ///     * Enforces a limit of 100 dice per roll.[^1]
+    ///
+    /// # Examples
+    /// ```
+    /// use die_parser::Roll;
+    /// use die_parser::RollError;
+    ///
+    /// let roll = Roll::parse_roll_any_die("2d30 + 5");
+    /// assert_eq!(roll, Ok(Roll::new(30, 2, 5)));
+    ///
+    /// let invalid_roll = Roll::parse_roll_any_die("1d1");
+    /// assert_eq!(invalid_roll, Err(RollError::DieTypeInvalid));
+    /// ```
+    /// [^1]: If you wish to allow more (or only allow less) than 100 dice per roll, use [`Roll::parse_roll_any_die_with_limit()`] instead.
+    pub fn parse_roll_any_die(input: &str) -> Result<Roll, RollError> {
+        Roll::parse_roll_any_die_with_limit(input, 100)
+    }
+
+    /// **Tries to parse input as roll notation (e.g. `1d3 + 5`), allowing any type of die.**
+    ///
+    /// * Behaves like [`Roll::parse_roll_with_limit()`], but accepts every die with at least 2 sides.
+    ///     * Enforces a custom limit of how many dice are allowed per roll `(0 = no limit)`.
+    ///
+    /// # Examples
+    /// ```
+    /// use die_parser::Roll;
+    /// use die_parser::RollError;
+    ///
+    /// let roll = Roll::parse_roll_any_die_with_limit("200d7", 500);
+    /// assert_eq!(roll, Ok(Roll::new(7, 200, 0)));
+    ///
+    /// let invalid_roll = Roll::parse_roll_any_die_with_limit("15d3", 10);
+    /// assert_eq!(invalid_roll, Err(RollError::DiceExceedLimit));
+    /// ```
+    pub fn parse_roll_any_die_with_limit(input: &str, max_dice: u16) -> Result<Roll, RollError> {
+        let result = Roll::parse_modified_roll(input)?;
+
+        // A die needs at least 2 sides to be worth rolling.
+        if result.number_of_sides < 2 {
+            return Err(RollError::DieTypeInvalid);
+        }
+
+        // The die type of the roll itself is the only one to check against.
+        result.check_parsed_roll_validity(max_dice, &[result.number_of_sides])?;
+        Ok(result)
+    }
+
+    /// **Tries to parse input as roll notation (e.g. `4d20 + 5`), requiring an explicit modifier sign.**
+    ///
+    /// * Behaves like [`Roll::parse_roll()`], but rejects numbers that are only separated by whitespaces.
+    ///     * `1d2 0` would otherwise be read as `1d20`.
+    ///
+    /// # Examples
+    /// ```
+    /// use die_parser::Roll;
+    /// use die_parser::RollError;
+    ///
+    /// let roll = Roll::parse_roll_strict_modifier_sign("2d6 + 3");
+    /// assert_eq!(roll, Ok(Roll::new(6, 2, 3)));
+    ///
+    /// let invalid_roll = Roll::parse_roll_strict_modifier_sign("1d2 0");
+    /// assert_eq!(invalid_roll, Err(RollError::ParsingError));
+    /// ```
+    pub fn parse_roll_strict_modifier_sign(input: &str) -> Result<Roll, RollError> {
+        // Look for a number directly followed by another number after a whitespace.
+        let words: Vec<&str> = input.split_whitespace().collect();
+        let ambiguous = words.windows(2).any(|pair| {
+            pair[0].ends_with(|c: char| c.is_ascii_digit())
+                && pair[1].starts_with(|c: char| c.is_ascii_digit())
+        });
+
+        if ambiguous {
+            return Err(RollError::ParsingError);
+        }
+
+        Roll::parse_roll(input)
+    }
+
+    /// **Tries to parse input as roll notation (e.g. `4d20 + 5`), reporting every error at once.**
+    ///
+    /// * Behaves like [`Roll::parse_roll()`], but collects all reasons the roll is invalid instead of just the first one.
+    /// * Useful for validating forms.
+    ///
+    /// # Examples
+    /// ```
+    /// use die_parser::Roll;
+    /// use die_parser::RollError;
+    ///
+    /// let roll = Roll::parse_roll_from_user_input("3d10 - 5");
+    /// assert_eq!(roll, Ok(Roll::new(10, 3, -5)));
+    ///
+    /// let invalid_roll = Roll::parse_roll_from_user_input("0d99");
+    /// assert_eq!(
+    ///     invalid_roll,
+    ///     Err(vec![RollError::DieTypeInvalid, RollError::NoDiceToRoll])
+    /// );
+    /// ```
+    pub fn parse_roll_from_user_input(input: &str) -> Result<Roll, Vec<RollError>> {
+        let result = Roll::parse_modified_roll(input).map_err(|e| vec![e])?;
+
+        let errors = result.roll_validity_errors(100, &DIE_TYPES, true);
+        if errors.is_empty() {
+            Ok(result)
+        } else {
+            Err(errors)
+        }
+    }
+
+    /// **Tries to parse input as a comma separated list of roll notations (e.g. `1d4, 2d6+3, 1d20`).**
+    ///
+    /// * Every roll is parsed and validated like [`Roll::parse_roll()`] does.
+    /// * Returns the first error encountered.
+    /// * An empty input results in no rolls.
+    ///
+    /// # Examples
+    /// ```
+    /// use die_parser::Roll;
+    /// use die_parser::RollError;
+    ///
+    /// let rolls = Roll::parse_many("1d4, 2d6+3, 1d20");
+    /// assert_eq!(
+    ///     rolls,
+    ///     Ok(vec![Roll::new(4, 1, 0), Roll::new(6, 2, 3), Roll::new(20, 1, 0)])
+    /// );
+    ///
+    /// let invalid_rolls = Roll::parse_many("1d4, 1d5");
+    /// assert_eq!(invalid_rolls, Err(RollError::DieTypeInvalid));
+    /// ```
+    pub fn parse_many(input: &str) -> Result<Vec<Roll>, RollError> {
+        if input.trim().is_empty() {
+            return Ok(Vec::new());
+        }
+
+        input.split(',').map(Roll::parse_roll).collect()
+    }
+}
+impl FromStr for Roll {
+    type Err = RollError;
+
+    /// Parses a [Roll] the same way [`Roll::parse_roll()`] does.
+    ///
+    /// # Examples
+    /// ```
+    /// use die_parser::Roll;
+    ///
+    /// let roll = "3d10 - 5".parse::<Roll>();
+    /// assert_eq!(roll, Ok(Roll::new(10, 3, -5)));
+    /// ```
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Roll::parse_roll(s)
+    }
+}
+impl TryFrom<&str> for Roll {
+    type Error = RollError;
+
+    /// Parses a [Roll] the same way [`Roll::parse_roll()`] does.
+    ///
+    /// # Examples
+    /// ```
+    /// use die_parser::Roll;
+    ///
+    /// assert_eq!(Roll::try_from("2d6+3"), Ok(Roll::new(6, 2, 3)));
+    ///
+    /// let roll: Roll = "1d20".try_into().unwrap();
+    /// assert_eq!(roll, Roll::new(20, 1, 0));
+    /// ```
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        Roll::parse_roll(value)
+    }
+}
+impl TryFrom<String> for Roll {
+    type Error = RollError;
+
+    /// Parses a [Roll] the same way [`Roll::parse_roll()`] does.
+    ///
+    /// # Examples
+    /// ```
+    /// use die_parser::Roll;
+    ///
+    /// let roll = Roll::try_from(String::from("2d6+3"));
+    /// assert_eq!(roll, Ok(Roll::new(6, 2, 3)));
+    /// ```
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        Roll::parse_roll(&value)
+    }
+}
+impl Add for Roll {
+    type Output = Result<Roll, RollError>;
+
+    /// Combines two rolls of the same type of die, summing their dice and modifiers.
+    ///
+    /// * Returns [`RollError::ConflictingOptions`] if the dice differ in type, explode differently
+    ///   or only some of them are kept.
+    /// * Returns [`RollError::ModifierOverflow`] if the modifier would exceed the range of an `i32`.
+    ///
+    /// # Examples
+    /// ```
+    /// use die_parser::{Roll, RollError};
+    ///
+    /// assert_eq!(Roll::new(6, 2, 0) + Roll::new(6, 1, 3), Ok(Roll::new(6, 3, 3)));
+    /// assert_eq!(
+    ///     Roll::new(6, 1, 0) + Roll::new(8, 1, 0),
+    ///     Err(RollError::ConflictingOptions)
+    /// );
+    /// ```
+    fn add(self, rhs: Roll) -> Self::Output {
+        // Kept dice can't be told apart once the pools are merged.
+        let same_dice = self.number_of_sides == rhs.number_of_sides
+            && self.die_kind == rhs.die_kind
+            && self.exploding == rhs.exploding;
+        if !same_dice || self.keep_mode.is_some() || rhs.keep_mode.is_some() {
+            return Err(RollError::ConflictingOptions);
+        }
+
+        self.add_dice(rhs.number_of_dice)?
+            .add_modifier(rhs.modifier)
+    }
+}
+impl fmt::Display for Roll {
+    /// Writes the roll as [`Roll::to_notation()`] does (e.g. `3d10-5`).
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.to_notation())
+    }
+}
+
+/// How the faces of the dice of a [Roll] are read.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum DieKind {
+    /// A die showing `1` up to its amount of sides.
+    #[default]
+    Standard,
+    /// A Fate die (e.g. `4dF`), which has three sides showing -1, 0 and +1.
+    Fate,
+}
+impl DieKind {
+    /// The value added to every face of a die, which is shown as `1` up to its amount of sides.
+    pub(crate) fn face_offset(&self) -> i32 {
+        match self {
+            Self::Standard => 0,
+            Self::Fate => -2,
+        }
+    }
+}
+
+/// Which of the rolled dice of a [Roll] count towards the result.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum KeepMode {
+    /// Keeps the given amount of the highest dice (e.g. `4d6kh3`).
+    Highest(u16),
+    /// Keeps the given amount of the lowest dice (e.g. `2d20kl1`).
+    Lowest(u16),
+    /// Drops the given amount of the lowest dice (e.g. `4d6dl1`).
+    DropLowest(u16),
+    /// Drops the given amount of the highest dice (e.g. `6d8dh2`).
+    DropHighest(u16),
+}
+impl fmt::Display for KeepMode {
+    /// Writes the keep or drop suffix of the roll notation (e.g. `kh3` or `dl1`).
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Highest(n) => write!(f, "kh{n}"),
+            Self::Lowest(n) => write!(f, "kl{n}"),
+            Self::DropLowest(n) => write!(f, "dl{n}"),
+            Self::DropHighest(n) => write!(f, "dh{n}"),
+        }
+    }
 }
 
 /// The different types of errors that may occur trying to construct a [Roll] from a given input string.
 #[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum RollError {
     /// Signifies that the inputted die type did not match any of the valid types.
     ///
@@ -173,38 +797,117 @@ pub enum RollError {
     /// ```
     /// use die_parser::{Roll, RollError};
     ///
-    /// let invalid_roll = Roll::parse_roll("1d50");
-    /// assert_eq!(invalid_roll, Err(RollError::DieTypeInvalid));
+    /// let invalid_roll = Roll::parse_roll("1d50");
+    /// assert_eq!(invalid_roll, Err(RollError::DieTypeInvalid));
+    /// ```
+    DieTypeInvalid,
+    /// Signifies that the requested amount of dice exceeded the set limit.
+    /// # Example
+    /// ```
+    /// use die_parser::{Roll, RollError};
+    ///
+    /// let invalid_roll = Roll::parse_roll("9001d20");
+    /// assert_eq!(invalid_roll, Err(RollError::DiceExceedLimit));
+    /// ```
+    DiceExceedLimit,
+    /// Signifies that the requested amount of dice was less than 1.
+    /// # Example
+    /// ```
+    /// use die_parser::{Roll, RollError};
+    ///
+    /// let invalid_roll = Roll::parse_roll("0d20");
+    /// assert_eq!(invalid_roll, Err(RollError::NoDiceToRoll));
+    /// ```
+    NoDiceToRoll,
+    /// Signifies that the input string was malformed.
+    /// # Example
+    /// ```
+    /// use die_parser::{Roll, RollError};
+    ///
+    /// let invalid_roll = Roll::parse_roll("4invalid_charactersd20+5");
+    /// assert_eq!(invalid_roll, Err(RollError::ParsingError));
+    ///
+    /// ```
+    ParsingError,
+    /// Signifies that applying a modifier would exceed the range of an `i32`.
+    /// # Example
+    /// ```
+    /// use die_parser::{Roll, RollError};
+    ///
+    /// let invalid_roll = Roll::parse_roll_with_fortune_point("1d20+5", i32::MAX);
+    /// assert_eq!(invalid_roll, Err(RollError::ModifierOverflow));
     /// ```
-    DieTypeInvalid,
-    /// Signifies that the requested amount of dice exceeded the set limit.
+    ModifierOverflow,
+    /// Signifies that the modifier was outside of the range a game system allows.
     /// # Example
     /// ```
     /// use die_parser::{Roll, RollError};
     ///
-    /// let invalid_roll = Roll::parse_roll("9001d20");
-    /// assert_eq!(invalid_roll, Err(RollError::DiceExceedLimit));
+    /// let invalid_roll = Roll::parse_roll_from_opend6_notation("3D+3");
+    /// assert_eq!(invalid_roll, Err(RollError::ModifierExceedsLimit));
     /// ```
-    DiceExceedLimit,
-    /// Signifies that the requested amount of dice was less than 1.
+    ModifierExceedsLimit,
+    /// Signifies that the input combined options that can't be used together.
     /// # Example
     /// ```
     /// use die_parser::{Roll, RollError};
     ///
-    /// let invalid_roll = Roll::parse_roll("0d20");
-    /// assert_eq!(invalid_roll, Err(RollError::NoDiceToRoll));
+    /// let invalid_roll = Roll::parse_roll("4d6kh3dl1");
+    /// assert_eq!(invalid_roll, Err(RollError::ConflictingOptions));
     /// ```
-    NoDiceToRoll,
-    /// Signifies that the input string was malformed.
+    ConflictingOptions,
+    /// Signifies that the amount of kept or dropped dice doesn't leave out at least one die.
     /// # Example
     /// ```
     /// use die_parser::{Roll, RollError};
     ///
-    /// let invalid_roll = Roll::parse_roll("4invalid_charactersd20+5");
-    /// assert_eq!(invalid_roll, Err(RollError::ParsingError));
+    /// let invalid_roll = Roll::parse_roll("4d6kh4");
+    /// assert_eq!(invalid_roll, Err(RollError::KeepCountInvalid));
+    /// ```
+    KeepCountInvalid,
+}
+impl RollError {
+    /// Maps the error to an exit code for command line tools.
     ///
+    /// | Error                    | Exit code |
+    /// |--------------------------|-----------|
+    /// | [`ParsingError`]         | `1`       |
+    /// | [`DieTypeInvalid`]       | `2`       |
+    /// | [`DiceExceedLimit`]      | `3`       |
+    /// | [`NoDiceToRoll`]         | `4`       |
+    /// | [`ModifierOverflow`]     | `5`       |
+    /// | [`ModifierExceedsLimit`] | `6`       |
+    /// | [`ConflictingOptions`]   | `7`       |
+    /// | [`KeepCountInvalid`]     | `8`       |
+    ///
+    /// # Example
     /// ```
-    ParsingError,
+    /// use die_parser::Roll;
+    ///
+    /// let error = Roll::parse_roll("1d50").unwrap_err();
+    /// assert_eq!(error.to_exit_code(), 2);
+    /// ```
+    ///
+    /// [`ParsingError`]: RollError::ParsingError
+    /// [`DieTypeInvalid`]: RollError::DieTypeInvalid
+    /// [`DiceExceedLimit`]: RollError::DiceExceedLimit
+    /// [`NoDiceToRoll`]: RollError::NoDiceToRoll
+    /// [`ModifierOverflow`]: RollError::ModifierOverflow
+    /// [`ModifierExceedsLimit`]: RollError::ModifierExceedsLimit
+    /// [`ConflictingOptions`]: RollError::ConflictingOptions
+    /// [`KeepCountInvalid`]: RollError::KeepCountInvalid
+    pub fn to_exit_code(&self) -> i32 {
+        match self {
+            Self::ParsingError => 1,
+            Self::DieTypeInvalid => 2,
+            Self::DiceExceedLimit => 3,
+            Self::NoDiceToRoll => 4,
+            Self::ModifierOverflow => 5,
+            Self::ModifierExceedsLimit => 6,
+            Self::ConflictingOptions => 7,
+            Self::KeepCountInvalid => 8,
+        }
+    }
 }
 impl fmt::Display for RollError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
@@ -213,40 +916,124 @@ impl fmt::Display for RollError {
             Self::DiceExceedLimit => write!(f, "Amount of dice exceeds the specified limit."),
             Self::NoDiceToRoll => write!(f, "Can't roll less than 1 die."),
             Self::ParsingError => write!(f, "Failed to parse the input string."),
+            Self::ModifierOverflow => write!(f, "Modifier value exceeds i32 range."),
+            Self::ModifierExceedsLimit => write!(f, "Modifier exceeds the allowed range."),
+            Self::ConflictingOptions => write!(f, "The given options can't be combined."),
+            Self::KeepCountInvalid => write!(f, "Amount of kept or dropped dice is invalid."),
         }
     }
 }
 impl std::error::Error for RollError {}
 
+/// Returns the first of the given errors, if any.
+fn first_error(errors: Vec<RollError>) -> Result<(), RollError> {
+    match errors.into_iter().next() {
+        Some(e) => Err(e),
+        // Checks passed.
+        None => Ok(()),
+    }
+}
+
 /// Parse a `u16` from the start of the input string.
 fn parse_numbers(input: &str) -> IResult<&str, u16> {
     map_res(digit1, u16::from_str)(input)
 }
 
-/// Tries to parse die type and amount of dice from a notated die roll (e.g. `4d20`).
+/// Tries to parse die type and amount of dice from a notated die roll (e.g. `4d20` or `4D20`).
+///
+/// A missing amount of dice is read as a single die (e.g. `d20` is read as `1d20`).
 fn parse_simple_roll(s: &str) -> IResult<&str, (u16, u16)> {
-    let parser = separated_pair(parse_numbers, char('d'), parse_numbers);
+    let parser = separated_pair(opt(parse_numbers), one_of("dD"), parse_numbers);
     map(parser, |(number_of_dice, number_of_sides)| {
-        (number_of_dice, number_of_sides)
+        (number_of_dice.unwrap_or(1), number_of_sides)
     })(s)
 }
 
+/// Tries to parse die type and amount of dice of either standard or Fate dice (e.g. `4d20` or `4dF`).
+///
+/// Fate dice are read as three-sided dice, percentile dice (e.g. `2d%`) as hundred-sided dice.
+fn parse_dice(s: &str) -> IResult<&str, ((u16, u16), DieKind)> {
+    branch::alt((
+        map(parse_simple_roll, |dice| (dice, DieKind::Standard)),
+        map(parse_fate_roll, |number_of_dice| {
+            ((number_of_dice, 3), DieKind::Fate)
+        }),
+        map(parse_percentile_roll, |number_of_dice| {
+            ((number_of_dice, 100), DieKind::Standard)
+        }),
+    ))(s)
+}
+
+/// Tries to parse the amount of dice from a notated roll of Fate dice (e.g. `4dF` or `4df`).
+///
+/// A missing amount of dice is read as a single die (e.g. `dF` is read as `1dF`).
+fn parse_fate_roll(s: &str) -> IResult<&str, u16> {
+    let parser = separated_pair(opt(parse_numbers), one_of("dD"), one_of("fF"));
+    map(parser, |(number_of_dice, _)| number_of_dice.unwrap_or(1))(s)
+}
+
+/// Tries to parse the amount of dice from a notated roll of percentile dice (e.g. `2d%`).
+///
+/// A missing amount of dice is read as a single die (e.g. `d%` is read as `1d%`).
+fn parse_percentile_roll(s: &str) -> IResult<&str, u16> {
+    let parser = separated_pair(opt(parse_numbers), one_of("dD"), char('%'));
+    map(parser, |(number_of_dice, _)| number_of_dice.unwrap_or(1))(s)
+}
+
+/// Tries to parse the optional keep or drop part of a notated die roll (e.g. `kh3` or `dl1`).
+fn parse_keep_mode(s: &str) -> IResult<&str, Option<KeepMode>> {
+    opt(branch::alt((
+        map(preceded(tag("kh"), parse_numbers), KeepMode::Highest),
+        map(preceded(tag("kl"), parse_numbers), KeepMode::Lowest),
+        map(preceded(tag("dl"), parse_numbers), KeepMode::DropLowest),
+        map(preceded(tag("dh"), parse_numbers), KeepMode::DropHighest),
+    )))(s)
+}
+
+/// Parse the value of a modifier from the start of the input string.
+///
+/// The value is wider than an `i32`, so that `i32::MIN` can be negated from it.
+fn parse_modifier_value(input: &str) -> IResult<&str, i64> {
+    map_res(digit1, i64::from_str)(input)
+}
+
 /// Looks for modifiers operator.
 fn parse_operator(s: &str) -> IResult<&str, &str> {
     branch::alt((tag("+"), tag("-"), tag("")))(s)
 }
 
-/// Tries to parse the modifier part of a notated die roll (e.g. `+5`).
-fn parse_modifier(s: &str) -> IResult<&str, i32> {
-    // Split operator and modifier.
-    let (remainder, operator) = parse_operator(s).unwrap();
+/// Tries to parse the modifier part of a notated die roll (e.g. `+5`), adding up chained modifiers (e.g. `+5-2`).
+///
+/// Returns [`RollError::ModifierOverflow`] if the sum leaves the range of an `i32` at any point.
+fn parse_modifier(s: &str) -> Result<(&str, i32), RollError> {
+    let mut remainder = s;
+    let mut modifier: i32 = 0;
+
+    loop {
+        // Split operator and modifier.
+        let (after_operator, operator) = parse_operator(remainder).unwrap();
+
+        // Return the sum so far if no operator signalling another modifier was found.
+        if operator.is_empty() {
+            return Ok((remainder, modifier));
+        }
+
+        let (after_value, value) = match parse_modifier_value(after_operator) {
+            Ok(v) => v,
+            Err(_) => return Err(RollError::ParsingError),
+        };
+
+        // Generate i32.
+        let sum = match operator {
+            "+" => i64::from(modifier).checked_add(value),
+            _ => i64::from(modifier).checked_sub(value),
+        };
+        modifier = match sum.and_then(|sum| i32::try_from(sum).ok()) {
+            Some(modifier) => modifier,
+            None => return Err(RollError::ModifierOverflow),
+        };
 
-    // Generate i32.
-    match operator {
-        "+" => map(parse_numbers, |modifier| modifier as i32)(remainder),
-        "-" => map(parse_numbers, |modifier| -(modifier as i32))(remainder),
-        // Return 0 as modifier if no operator signalling a modifier was found.
-        _ => Ok((remainder, 0)),
+        remainder = after_value;
     }
 }
 
@@ -257,6 +1044,8 @@ mod tests {
     fn test_parse_simple_roll() {
         let tests = [
             ("4d20", (4, 20), ""),
+            ("4D20", (4, 20), ""),
+            ("d20", (1, 20), ""),
             ("4d20remainder_text", (4, 20), "remainder_text"),
         ];
 
@@ -272,12 +1061,14 @@ mod tests {
         let tests_positive = [
             ("+5", 5, ""),
             ("+5remainder_text", 5, "remainder_text"),
+            ("+70000", 70000, ""),
             ("", 0, ""),
             ("random_unparsable", 0, "random_unparsable"),
         ];
         let tests_negative = [
             ("-5", -5, ""),
             ("-5remainder_text", -5, "remainder_text"),
+            ("-2147483648", i32::MIN, ""),
             ("", 0, ""),
             ("random_unparsable", 0, "random_unparsable"),
         ];
@@ -293,6 +1084,27 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_parse_modifier_chain() {
+        let tests = [
+            ("+10-4+2", 8, ""),
+            ("-1-1-1", -3, ""),
+            ("+3-1 random_stuff", 2, " random_stuff"),
+            ("+2147483647-2147483648", -1, ""),
+        ];
+
+        for (input, expected_output, expected_remaining_input) in tests {
+            let (remaining_input, output) = parse_modifier(input).unwrap();
+            assert_eq!(remaining_input, expected_remaining_input);
+            assert_eq!(output, expected_output);
+        }
+
+        assert_eq!(Roll::parse_roll("1d6+10-4+2"), Ok(Roll::new(6, 1, 8)));
+
+        let chain = format!("1d6{}", "+65535".repeat(40000));
+        assert_eq!(Roll::parse_roll(&chain), Err(RollError::ModifierOverflow));
+    }
+
     #[test]
     fn test_parse_modified_roll() {
         let tests = [
@@ -302,6 +1114,7 @@ mod tests {
             ("4 d 10  - 3", Roll::new(10, 4, -3)),
             ("4d10+3 random_stuff", Roll::new(10, 4, 3)),
             ("4d10-3 random_stuff", Roll::new(10, 4, -3)),
+            ("0d20", Roll::new(20, 0, 0)),
         ];
 
         for (input, expected_output) in tests {
@@ -319,6 +1132,9 @@ mod tests {
             ("4d10  - unparsable_modifier", RollError::ParsingError),
             ("4dinvalid_die_type", RollError::ParsingError),
             ("invalid_die_amountd20", RollError::ParsingError),
+            ("4d10+2147483648", RollError::ModifierOverflow),
+            ("4d10-2147483647-2", RollError::ModifierOverflow),
+            ("4d10+1-", RollError::ParsingError),
         ];
 
         for (input, expected_output) in tests {
@@ -332,6 +1148,7 @@ mod tests {
         let tests = [
             ("4d5", RollError::DieTypeInvalid),
             ("0d20", RollError::NoDiceToRoll),
+            ("0d20 + 5", RollError::NoDiceToRoll),
             ("0d5", RollError::DieTypeInvalid),
             ("9001d20", RollError::DiceExceedLimit),
         ];
@@ -341,4 +1158,511 @@ mod tests {
             assert_eq!(output, expected_output);
         }
     }
+
+    #[test]
+    fn test_parse_roll_uppercase_separator() {
+        let tests = [("2D6", "2d6"), ("4D20+5", "4d20+5"), ("1D100-3", "1d100-3")];
+
+        for (input, lowercase_input) in tests {
+            let output = Roll::parse_roll(input);
+            assert!(output.is_ok());
+            assert_eq!(output, Roll::parse_roll(lowercase_input));
+        }
+    }
+
+    #[test]
+    fn test_copy() {
+        let roll = Roll::new(20, 1, 5);
+        let copy = roll;
+
+        assert_eq!(roll, copy);
+        assert_eq!(roll.clone(), copy);
+    }
+
+    #[test]
+    fn test_hash() {
+        use std::collections::hash_map::DefaultHasher;
+        use std::hash::{Hash, Hasher};
+
+        fn hash_of(roll: Roll) -> u64 {
+            let mut hasher = DefaultHasher::new();
+            roll.hash(&mut hasher);
+            hasher.finish()
+        }
+
+        let tests = [
+            (Roll::new(20, 1, 5), Roll::new(20, 1, 5), true),
+            (Roll::new(6, 2, 0), Roll::parse_roll("2d6").unwrap(), true),
+            (Roll::new(20, 1, 5), Roll::new(20, 1, -5), false),
+            (Roll::new(6, 2, 0), Roll::new(2, 6, 0), false),
+            (Roll::new(8, 3, 1), Roll::new(10, 3, 1), false),
+        ];
+
+        for (roll, other_roll, expected_output) in tests {
+            assert_eq!(hash_of(roll) == hash_of(other_roll), expected_output);
+        }
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde() {
+        let tests = [
+            Roll::new(20, 4, 5),
+            Roll::new(6, 1, 0),
+            Roll::new(100, 2, -10),
+        ];
+
+        for roll in tests {
+            let json = serde_json::to_string(&roll).unwrap();
+            assert_eq!(serde_json::from_str::<Roll>(&json).unwrap(), roll);
+        }
+
+        let json = r#"{"number_of_sides":20,"number_of_dice":4,"modifier":5}"#;
+        assert_eq!(
+            serde_json::from_str::<Roll>(json).unwrap(),
+            Roll::new(20, 4, 5)
+        );
+
+        let error = RollError::DieTypeInvalid;
+        assert_eq!(
+            serde_json::to_string(&error).unwrap(),
+            r#""DieTypeInvalid""#
+        );
+        assert_eq!(
+            serde_json::from_str::<RollError>(r#""DieTypeInvalid""#).unwrap(),
+            error
+        );
+    }
+
+    #[test]
+    fn test_parse_roll_bare_die() {
+        let tests = [
+            ("d6", Ok(Roll::new(6, 1, 0))),
+            ("d8+3", Ok(Roll::new(8, 1, 3))),
+            ("d100-10", Ok(Roll::new(100, 1, -10))),
+            ("d2", Ok(Roll::new(2, 1, 0))),
+            ("1d6", Ok(Roll::new(6, 1, 0))),
+            ("d%", Ok(Roll::new(100, 1, 0))),
+            ("3D%+5", Ok(Roll::new(100, 3, 5))),
+            ("d%!kh1", Err(RollError::KeepCountInvalid)),
+            ("d5", Err(RollError::DieTypeInvalid)),
+        ];
+
+        for (input, expected_output) in tests {
+            assert_eq!(Roll::parse_roll(input), expected_output);
+        }
+    }
+
+    #[test]
+    fn test_parse_roll_exploding() {
+        let tests = [
+            ("2d6!", Ok(Roll::new(6, 2, 0).exploding())),
+            ("d8! + 2", Ok(Roll::new(8, 1, 2).exploding())),
+            ("2d6", Ok(Roll::new(6, 2, 0))),
+        ];
+
+        for (input, expected_output) in tests {
+            assert_eq!(Roll::parse_roll(input), expected_output);
+        }
+    }
+
+    #[test]
+    fn test_parse_roll_keep() {
+        let tests = [
+            ("4d6kh3", Ok(Roll::new(6, 4, 0).keep(KeepMode::Highest(3)))),
+            (
+                "2d20kl1 + 2",
+                Ok(Roll::new(20, 2, 2).keep(KeepMode::Lowest(1))),
+            ),
+            (
+                "3d6!kh2",
+                Ok(Roll::new(6, 3, 0).exploding().keep(KeepMode::Highest(2))),
+            ),
+            ("4d6kh4", Err(RollError::KeepCountInvalid)),
+            ("4d6kl5", Err(RollError::KeepCountInvalid)),
+            ("4d6kh0", Err(RollError::KeepCountInvalid)),
+            ("1d6kh1", Err(RollError::KeepCountInvalid)),
+            ("4d6kh3!", Err(RollError::ParsingError)),
+            ("4d6kh3+1!", Err(RollError::ParsingError)),
+            ("4d6+1kh3", Err(RollError::ParsingError)),
+        ];
+
+        for (input, expected_output) in tests {
+            assert_eq!(Roll::parse_roll(input), expected_output);
+        }
+    }
+
+    #[test]
+    fn test_parse_roll_drop() {
+        let tests = [
+            (
+                "4d6dl1",
+                Ok(Roll::new(6, 4, 0).keep(KeepMode::DropLowest(1))),
+            ),
+            (
+                "6d8dh2",
+                Ok(Roll::new(8, 6, 0).keep(KeepMode::DropHighest(2))),
+            ),
+            ("3d6dl3", Err(RollError::KeepCountInvalid)),
+            ("3d6dl0", Err(RollError::KeepCountInvalid)),
+            ("3d6dh0", Err(RollError::KeepCountInvalid)),
+            ("4d6kh3dl1", Err(RollError::ConflictingOptions)),
+            ("4d6dl1kl2", Err(RollError::ConflictingOptions)),
+        ];
+
+        for (input, expected_output) in tests {
+            assert_eq!(Roll::parse_roll(input), expected_output);
+        }
+    }
+
+    #[test]
+    fn test_parse_roll_fate() {
+        let tests = [
+            ("dF", Ok(Roll::new_fate(1, 0))),
+            ("4dF+2", Ok(Roll::new_fate(4, 2))),
+            ("4df - 1", Ok(Roll::new_fate(4, -1))),
+            ("4dF ++ 2", Err(RollError::ParsingError)),
+            ("4dF!", Err(RollError::ConflictingOptions)),
+            ("0dF", Err(RollError::NoDiceToRoll)),
+            ("4d3", Err(RollError::DieTypeInvalid)),
+        ];
+
+        for (input, expected_output) in tests {
+            assert_eq!(Roll::parse_roll(input), expected_output);
+        }
+    }
+
+    #[test]
+    fn test_fate_validity_with_die_types() {
+        let fate_roll = Roll::new_fate(4, 0);
+
+        assert_eq!(
+            fate_roll.check_roll_validity(100),
+            Err(RollError::DieTypeInvalid)
+        );
+        assert_eq!(
+            fate_roll.check_roll_validity_with_die_types(100, &[3, 6]),
+            Err(RollError::DieTypeInvalid)
+        );
+        assert_eq!(
+            fate_roll.check_roll_validity_with_die_types(100, &[6, 20]),
+            Err(RollError::DieTypeInvalid)
+        );
+        assert_eq!(Roll::parse_roll_any_die("4dF"), Ok(fate_roll));
+    }
+
+    #[test]
+    fn test_kept_results() {
+        let results = [5, 2, 6, 1];
+        let tests = [
+            (Roll::new(6, 4, 0), vec![1, 2, 5, 6]),
+            (Roll::new(6, 4, 0).keep(KeepMode::Highest(3)), vec![2, 5, 6]),
+            (Roll::new(6, 4, 0).keep(KeepMode::Lowest(1)), vec![1]),
+            (
+                Roll::new(6, 4, 0).keep(KeepMode::DropLowest(1)),
+                vec![2, 5, 6],
+            ),
+            (Roll::new(6, 4, 0).keep(KeepMode::DropHighest(3)), vec![1]),
+        ];
+
+        for (roll, expected_output) in tests {
+            assert_eq!(roll.kept_results(&results), expected_output);
+        }
+    }
+
+    #[test]
+    fn test_parse_roll_with_limit() {
+        let tests = [
+            (("9001d20", 0), Ok(Roll::new(20, 9001, 0))),
+            (("50d20", 50), Ok(Roll::new(20, 50, 0))),
+            (("51d20", 50), Err(RollError::DiceExceedLimit)),
+            (("0d20", 0), Err(RollError::NoDiceToRoll)),
+        ];
+
+        for ((input, max_dice), expected_output) in tests {
+            let output = Roll::parse_roll_with_limit(input, max_dice);
+            assert_eq!(output, expected_output);
+        }
+    }
+
+    #[test]
+    fn test_from_str() {
+        let tests = [
+            ("2d6", Ok(Roll::new(6, 2, 0))),
+            ("4d20 + 5", Ok(Roll::new(20, 4, 5))),
+            ("101d20", Err(RollError::DiceExceedLimit)),
+            ("invalid", Err(RollError::ParsingError)),
+        ];
+
+        for (input, expected_output) in tests {
+            assert_eq!(input.parse::<Roll>(), expected_output);
+            assert_eq!(String::from(input).parse::<Roll>(), expected_output);
+            assert_eq!(Roll::from_str(input), expected_output);
+        }
+    }
+
+    #[test]
+    fn test_try_from() {
+        let tests = [
+            ("2d6+3", Ok(Roll::new(6, 2, 3))),
+            ("1d20", Ok(Roll::new(20, 1, 0))),
+            ("101d20", Err(RollError::DiceExceedLimit)),
+            ("invalid", Err(RollError::ParsingError)),
+        ];
+
+        for (input, expected_output) in tests {
+            assert_eq!(Roll::try_from(input), expected_output);
+            assert_eq!(Roll::try_from(String::from(input)), expected_output);
+        }
+    }
+
+    #[test]
+    fn test_display() {
+        let tests = [
+            (Roll::new(6, 2, 0), "2d6"),
+            (Roll::new(10, 3, -5), "3d10-5"),
+            (Roll::new(20, 1, 4), "1d20+4"),
+            (Roll::new(100, 1, 0), "1d100"),
+            (Roll::new(6, 2, 3).exploding(), "2d6!+3"),
+            (Roll::new(6, 4, 0).keep(KeepMode::Highest(3)), "4d6kh3"),
+            (
+                Roll::new(8, 6, 1).keep(KeepMode::DropHighest(2)),
+                "6d8dh2+1",
+            ),
+            (Roll::new_fate(4, 2), "4dF+2"),
+        ];
+
+        for (roll, expected_output) in tests {
+            let output = roll.to_string();
+            assert_eq!(output, expected_output);
+            assert_eq!(Roll::parse_roll(&output), Ok(roll));
+        }
+    }
+
+    #[test]
+    fn test_parse_roll_any_die() {
+        let tests = [
+            ("1d3", Ok(Roll::new(3, 1, 0))),
+            ("2d30+5", Ok(Roll::new(30, 2, 5))),
+            ("3d7 - 1", Ok(Roll::new(7, 3, -1))),
+            ("1d1", Err(RollError::DieTypeInvalid)),
+            ("1d0", Err(RollError::DieTypeInvalid)),
+            ("101d3", Err(RollError::DiceExceedLimit)),
+            ("0d3", Err(RollError::NoDiceToRoll)),
+        ];
+
+        for (input, expected_output) in tests {
+            assert_eq!(Roll::parse_roll_any_die(input), expected_output);
+        }
+
+        for input in ["1d3", "2d30+5"] {
+            assert_eq!(Roll::parse_roll(input), Err(RollError::DieTypeInvalid));
+        }
+    }
+
+    #[test]
+    fn test_parse_roll_any_die_with_limit() {
+        let tests = [
+            (("200d7", 0), Ok(Roll::new(7, 200, 0))),
+            (("50d3", 50), Ok(Roll::new(3, 50, 0))),
+            (("51d3", 50), Err(RollError::DiceExceedLimit)),
+        ];
+
+        for ((input, max_dice), expected_output) in tests {
+            let output = Roll::parse_roll_any_die_with_limit(input, max_dice);
+            assert_eq!(output, expected_output);
+        }
+    }
+
+    #[test]
+    fn test_to_notation() {
+        let tests = [
+            (Roll::new(6, 2, 0), "2d6"),
+            (Roll::new(20, 1, 4), "1d20+4"),
+            (Roll::new(10, 3, -5), "3d10-5"),
+            (Roll::new(100, 100, i32::MAX), "100d100+2147483647"),
+            (Roll::new(2, 1, i32::MIN), "1d2-2147483648"),
+        ];
+
+        for (roll, expected_output) in tests {
+            let output = roll.to_notation();
+            assert_eq!(output, expected_output);
+            assert_eq!(roll.to_string(), output);
+            assert_eq!(Roll::parse_roll(&output), Ok(roll));
+        }
+    }
+
+    #[test]
+    fn test_parse_roll_strict_modifier_sign() {
+        let tests = [
+            ("2d6+3", Ok(Roll::new(6, 2, 3))),
+            ("2 d 6 - 3", Ok(Roll::new(6, 2, -3))),
+            ("2d6 3", Err(RollError::ParsingError)),
+            ("1d2 0", Err(RollError::ParsingError)),
+        ];
+
+        for (input, expected_output) in tests {
+            let output = Roll::parse_roll_strict_modifier_sign(input);
+            assert_eq!(output, expected_output);
+        }
+    }
+
+    #[test]
+    fn test_parse_many() {
+        let tests = [
+            (
+                "1d4, 2d6+3, 1d20",
+                Ok(vec![
+                    Roll::new(4, 1, 0),
+                    Roll::new(6, 2, 3),
+                    Roll::new(20, 1, 0),
+                ]),
+            ),
+            ("1d4,2d6", Ok(vec![Roll::new(4, 1, 0), Roll::new(6, 2, 0)])),
+            ("2d6+3", Ok(vec![Roll::new(6, 2, 3)])),
+            ("", Ok(vec![])),
+            ("  ", Ok(vec![])),
+            ("1d4, 101d6", Err(RollError::DiceExceedLimit)),
+            ("1d5, 0d6", Err(RollError::DieTypeInvalid)),
+            ("1d4,,1d6", Err(RollError::ParsingError)),
+        ];
+
+        for (input, expected_output) in tests {
+            assert_eq!(Roll::parse_many(input), expected_output);
+        }
+    }
+
+    #[test]
+    fn test_err_parse_roll_from_user_input() {
+        let tests = [
+            (
+                "0d99",
+                vec![RollError::DieTypeInvalid, RollError::NoDiceToRoll],
+            ),
+            (
+                "101d7",
+                vec![RollError::DieTypeInvalid, RollError::DiceExceedLimit],
+            ),
+            ("101d20", vec![RollError::DiceExceedLimit]),
+            ("invalid", vec![RollError::ParsingError]),
+        ];
+
+        for (input, expected_output) in tests {
+            let output = Roll::parse_roll_from_user_input(input).unwrap_err();
+            assert_eq!(output, expected_output);
+        }
+    }
+
+    #[test]
+    fn test_add_and_remove_dice() {
+        let roll = Roll::new(20, 3, -1);
+
+        assert_eq!(roll.add_dice(1), Ok(Roll::new(20, 4, -1)));
+        assert_eq!(roll.add_dice(u16::MAX), Ok(Roll::new(20, u16::MAX, -1)));
+        assert_eq!(roll.remove_dice(2), Ok(Roll::new(20, 1, -1)));
+        assert_eq!(roll.remove_dice(3), Err(RollError::NoDiceToRoll));
+        assert_eq!(roll.remove_dice(u16::MAX), Err(RollError::NoDiceToRoll));
+    }
+
+    #[test]
+    fn test_add_modifier() {
+        let roll = Roll::new(20, 1, 5).exploding();
+
+        assert_eq!(roll.add_modifier(2), Ok(Roll::new(20, 1, 7).exploding()));
+        assert_eq!(roll.add_modifier(-5), Ok(Roll::new(20, 1, 0).exploding()));
+        assert_eq!(
+            roll.add_modifier(i32::MAX - 5),
+            Ok(Roll::new(20, 1, i32::MAX).exploding())
+        );
+        assert_eq!(
+            roll.add_modifier(i32::MAX),
+            Err(RollError::ModifierOverflow)
+        );
+
+        let error = RollError::ModifierOverflow;
+        assert_eq!(error.to_string(), "Modifier value exceeds i32 range.");
+        assert_eq!(error.to_exit_code(), 5);
+    }
+
+    #[test]
+    fn test_add() {
+        let tests = [
+            (
+                (Roll::new(6, 2, 0), Roll::new(6, 1, 3)),
+                Ok(Roll::new(6, 3, 3)),
+            ),
+            (
+                (Roll::new(8, 1, -1), Roll::new(8, 1, 1)),
+                Ok(Roll::new(8, 2, 0)),
+            ),
+            (
+                (
+                    Roll::new(6, 1, 0).exploding(),
+                    Roll::new(6, 1, 0).exploding(),
+                ),
+                Ok(Roll::new(6, 2, 0).exploding()),
+            ),
+            (
+                (Roll::new(6, 1, 0), Roll::new(8, 1, 0)),
+                Err(RollError::ConflictingOptions),
+            ),
+            (
+                (Roll::new(6, 1, 0), Roll::new(6, 1, 0).exploding()),
+                Err(RollError::ConflictingOptions),
+            ),
+            (
+                (
+                    Roll::new(6, 4, 0).keep(KeepMode::Highest(3)),
+                    Roll::new(6, 1, 0),
+                ),
+                Err(RollError::ConflictingOptions),
+            ),
+            (
+                (Roll::new(6, 1, i32::MAX), Roll::new(6, 1, 1)),
+                Err(RollError::ModifierOverflow),
+            ),
+        ];
+
+        for ((roll_a, roll_b), expected_output) in tests {
+            assert_eq!(roll_a + roll_b, expected_output);
+        }
+    }
+
+    #[test]
+    fn test_negate_dice_order() {
+        let tests = [
+            (Roll::new(20, 1, 5), Roll::new(1, 20, 5)),
+            (
+                Roll::new(6, 2, 0).exploding(),
+                Roll::new(2, 6, 0).exploding(),
+            ),
+            (
+                Roll::new(6, 4, 0).keep(KeepMode::Highest(3)),
+                Roll::new(4, 6, 0).keep(KeepMode::Highest(3)),
+            ),
+        ];
+
+        for (roll, expected_output) in tests {
+            assert_eq!(roll.negate_dice_order(), expected_output);
+        }
+    }
+
+    #[test]
+    fn test_to_succinct_string() {
+        let tests = [
+            (Roll::new(6, 1, 0), "d6"),
+            (Roll::new(100, 1, 0), "d%"),
+            (Roll::new(8, 2, 0), "2d8"),
+            (Roll::new(100, 3, 5), "3d%+5"),
+            (Roll::new(20, 1, -2), "d20-2"),
+            (Roll::new(6, 1, 1).exploding(), "d6!+1"),
+            (Roll::new(20, 2, 0).keep(KeepMode::Lowest(1)), "2d20kl1"),
+            (Roll::new_fate(1, 0), "dF"),
+        ];
+
+        for (roll, expected_output) in tests {
+            assert_eq!(roll.to_succinct_string(), expected_output);
+            assert_eq!(Roll::parse_roll(expected_output), Ok(roll));
+        }
+    }
 }
@@ -0,0 +1,697 @@
+use crate::{KeepMode, Roll, RollError, MAX_EXPLOSIONS};
+
+/// The highest amount of dice [`Roll::from_probability_target()`] will suggest.
+const MAX_DICE: u16 = 100;
+
+/// Explosions less likely than this are left out of probability calculations.
+const NEGLIGIBLE_PROBABILITY: f64 = 1e-20;
+
+impl Roll {
+    /// **Calculates the lowest result the roll can possibly produce.**
+    ///
+    /// * Only kept dice count towards the result.
+    ///
+    /// # Examples
+    /// ```
+    /// use die_parser::{KeepMode, Roll};
+    ///
+    /// assert_eq!(Roll::new(20, 2, 3).min_result(), 5);
+    /// assert_eq!(Roll::new(6, 1, -4).min_result(), -3);
+    /// assert_eq!(Roll::new(6, 4, 0).keep(KeepMode::Highest(3)).min_result(), 3);
+    /// ```
+    pub fn min_result(&self) -> i32 {
+        let lowest_face = match (self.number_of_sides, self.exploding) {
+            (0, _) => 0,
+            // A single side always explodes, all the way up to the limit.
+            (1, true) => i32::from(MAX_EXPLOSIONS) + 1,
+            _ => 1,
+        };
+        self.result_from_faces(lowest_face)
+    }
+
+    /// **Calculates the highest result the roll can possibly produce.**
+    ///
+    /// * Only kept dice count towards the result.
+    /// * Exploding dice are rolled again up to 100 times.
+    ///
+    /// # Examples
+    /// ```
+    /// use die_parser::{KeepMode, Roll};
+    ///
+    /// assert_eq!(Roll::new(20, 2, 3).max_result(), 43);
+    /// assert_eq!(Roll::new(6, 1, 0).exploding().max_result(), 606);
+    /// assert_eq!(Roll::new(6, 4, 0).keep(KeepMode::Highest(3)).max_result(), 18);
+    /// ```
+    pub fn max_result(&self) -> i32 {
+        let sides = i32::from(self.number_of_sides);
+        let highest_face = match self.exploding {
+            // Exploding dice saturate like `Roll::roll_individual()` does.
+            true => (sides * (i32::from(MAX_EXPLOSIONS) + 1)).min(i32::from(u16::MAX)),
+            false => sides,
+        };
+        self.result_from_faces(highest_face)
+    }
+
+    /// **Calculates the expected value of the roll's result.**
+    ///
+    /// # Examples
+    /// ```
+    /// use die_parser::{KeepMode, Roll};
+    ///
+    /// assert_eq!(Roll::new(12, 1, 0).average(), 6.5);
+    /// assert_eq!(Roll::new(6, 2, 0).average(), 7.0);
+    ///
+    /// let average = Roll::new(6, 4, 0).keep(KeepMode::Highest(3)).average();
+    /// assert!((average - 12.2446).abs() < 1e-4);
+    /// ```
+    pub fn average(&self) -> f64 {
+        let (kept_dice, _) = self.kept_dice();
+        let offset = kept_dice as f64 * self.die_kind.face_offset() as f64;
+        self.face_sum_mean_and_variance().0 + offset + self.modifier as f64
+    }
+
+    /// **Calculates the variance of the roll's result.**
+    ///
+    /// The modifier does not affect the variance.
+    ///
+    /// # Examples
+    /// ```
+    /// use die_parser::Roll;
+    ///
+    /// assert_eq!(Roll::new(6, 1, 3).variance(), 35.0 / 12.0);
+    /// ```
+    pub fn variance(&self) -> f64 {
+        self.face_sum_mean_and_variance().1
+    }
+
+    /// **Calculates the standard deviation of the roll's result.**
+    ///
+    /// # Examples
+    /// ```
+    /// use die_parser::Roll;
+    ///
+    /// assert_eq!(Roll::new(2, 1, 0).standard_deviation(), 0.5);
+    /// ```
+    pub fn standard_deviation(&self) -> f64 {
+        self.variance().sqrt()
+    }
+
+    /// **Calculates the probability of the roll's result meeting or exceeding `target`.**
+    ///
+    /// # Examples
+    /// ```
+    /// use die_parser::Roll;
+    ///
+    /// let roll = Roll::new(20, 1, 5);
+    /// assert!((roll.probability_of_at_least(16) - 0.5).abs() < 1e-9);
+    /// assert_eq!(roll.probability_of_at_least(6), 1.0);
+    /// ```
+    pub fn probability_of_at_least(&self, target: i32) -> f64 {
+        // Result the faces of the kept dice themselves have to reach.
+        let (kept_dice, _) = self.kept_dice();
+        let offset = kept_dice as i64 * self.die_kind.face_offset() as i64;
+        let needed = target as i64 - self.modifier as i64 - offset;
+        if needed <= 0 {
+            return 1.0;
+        }
+        // Past the results with more than a negligible chance, nothing is left to sum up.
+        let highest_face = self
+            .die_runs()
+            .iter()
+            .map(|&(first, length, _)| first + length - 1)
+            .max()
+            .unwrap_or(0);
+        if needed > kept_dice as i64 * highest_face as i64 {
+            return 0.0;
+        }
+
+        // Sums reaching `needed` are all collected at its index.
+        let distribution = self.face_sum_distribution(needed as usize);
+        distribution[needed as usize].min(1.0)
+    }
+
+    /// **Finds the amount of dice whose roll most closely meets or exceeds `target` with the given `probability`.**
+    ///
+    /// * Checks for validity of die type.[^1]
+    /// * Suggests up to 100 dice.
+    ///
+    /// # Examples
+    /// ```
+    /// use die_parser::Roll;
+    ///
+    /// let roll = Roll::from_probability_target(0.5, 7, 6);
+    /// assert_eq!(roll, Ok(Roll::new(6, 2, 0)));
+    /// ```
+    /// [^1]: Valid die types are: d2, d4, d6, d8, d10, d12, d20, d100
+    pub fn from_probability_target(
+        probability: f64,
+        target: i32,
+        sides: u16,
+    ) -> Result<Roll, RollError> {
+        Roll::new(sides, 1, 0).check_roll_validity(MAX_DICE)?;
+
+        let probability_for = |dice| Roll::new(sides, dice, 0).probability_of_at_least(target);
+
+        // More dice only ever make reaching the target more likely,
+        // so search for the fewest dice that reach the requested probability.
+        let (mut low, mut high) = (1, MAX_DICE);
+        while low < high {
+            let middle = low + (high - low) / 2;
+            if probability_for(middle) < probability {
+                low = middle + 1;
+            } else {
+                high = middle;
+            }
+        }
+
+        // One die less might come closer to the requested probability.
+        let mut number_of_dice = low;
+        if low > 1
+            && (probability - probability_for(low - 1)).abs()
+                < (probability_for(low) - probability).abs()
+        {
+            number_of_dice = low - 1;
+        }
+
+        Ok(Roll::new(sides, number_of_dice, 0))
+    }
+}
+
+impl Roll {
+    /// Splits the results of a single die into runs of results sharing the same probability.
+    ///
+    /// * Every run holds its lowest result, its amount of results and the probability of each result.
+    /// * A die without sides always shows 0.
+    /// * Exploding dice are rolled again up to 100 times, like [`Roll::roll_individual()`] does.
+    ///     * Explosions whose chance is negligible are left out.
+    ///     * Results saturate at `u16::MAX`.
+    fn die_runs(&self) -> Vec<(usize, usize, f64)> {
+        let sides = self.number_of_sides as usize;
+        if sides == 0 {
+            return vec![(0, 1, 1.0)];
+        }
+
+        let face_probability = 1.0 / sides as f64;
+        if !self.exploding {
+            return vec![(1, sides, face_probability)];
+        }
+
+        // After showing the highest face `explosions` times, the die stops on a lower face,
+        // unless it can't be rolled again.
+        let rerolls = MAX_EXPLOSIONS as usize;
+        let mut runs = Vec::new();
+        let mut probability = face_probability;
+        for explosions in 0..=rerolls {
+            if probability < NEGLIGIBLE_PROBABILITY {
+                break;
+            }
+
+            let faces = if explosions == rerolls {
+                sides
+            } else {
+                sides - 1
+            };
+            push_saturating_run(&mut runs, sides * explosions + 1, faces, probability);
+            probability *= face_probability;
+        }
+
+        runs
+    }
+
+    /// Calculates the probability of each result of a single die, stored at the result's index.
+    ///
+    /// The results are the same as those of [`Roll::die_runs()`].
+    fn die_distribution(&self) -> Vec<f64> {
+        let runs = self.die_runs();
+        let highest = runs.iter().map(|&(first, length, _)| first + length - 1);
+        let mut distribution = vec![0.0; highest.max().unwrap_or(0) + 1];
+
+        for (first, length, probability) in runs {
+            for entry in &mut distribution[first..first + length] {
+                *entry += probability;
+            }
+        }
+
+        distribution
+    }
+
+    /// Returns the amount of kept dice and whether the highest ones are kept.
+    pub(crate) fn kept_dice(&self) -> (u16, bool) {
+        let dice = self.number_of_dice;
+        match self.keep_mode {
+            None => (dice, true),
+            Some(KeepMode::Highest(n)) => (n.min(dice), true),
+            Some(KeepMode::Lowest(n)) => (n.min(dice), false),
+            Some(KeepMode::DropLowest(n)) => (dice.saturating_sub(n), true),
+            Some(KeepMode::DropHighest(n)) => (dice.saturating_sub(n), false),
+        }
+    }
+
+    /// Calculates the result of every kept die showing `face`.
+    fn result_from_faces(&self, face: i32) -> i32 {
+        let (kept_dice, _) = self.kept_dice();
+        i32::from(kept_dice)
+            .saturating_mul(face.saturating_add(self.die_kind.face_offset()))
+            .saturating_add(self.modifier)
+    }
+
+    /// Calculates the mean and variance of the sum of the kept faces.
+    fn face_sum_mean_and_variance(&self) -> (f64, f64) {
+        if self.keep_mode.is_none() {
+            // The dice are independent, so their moments simply add up.
+            let (mean, variance) = moments(&self.die_distribution());
+            let dice = self.number_of_dice as f64;
+            return (dice * mean, dice * variance);
+        }
+
+        let (kept_dice, keep_highest) = self.kept_dice();
+        kept_sum_moments(
+            &self.die_distribution(),
+            self.number_of_dice,
+            kept_dice,
+            keep_highest,
+        )
+    }
+
+    /// Calculates the probability of each possible sum of the kept faces, stored at the sum's index.
+    ///
+    /// All sums of at least `cap` are collected at the index `cap`.
+    fn face_sum_distribution(&self, cap: usize) -> Vec<f64> {
+        let (kept_dice, keep_highest) = self.kept_dice();
+
+        if kept_dice == self.number_of_dice {
+            return sum_distribution(&self.die_runs(), self.number_of_dice, cap);
+        }
+
+        let faces = self.die_distribution();
+        kept_sum_distribution(&faces, self.number_of_dice, kept_dice, keep_highest, cap)
+    }
+}
+
+/// Adds a run of results to the given runs, saturating results above `u16::MAX`.
+fn push_saturating_run(
+    runs: &mut Vec<(usize, usize, f64)>,
+    first: usize,
+    length: usize,
+    probability: f64,
+) {
+    let highest = u16::MAX as usize;
+    let unsaturated = length.min((highest + 1).saturating_sub(first));
+    if unsaturated > 0 {
+        runs.push((first, unsaturated, probability));
+    }
+    if unsaturated < length {
+        runs.push((highest, 1, probability * (length - unsaturated) as f64));
+    }
+}
+
+/// Calculates the probability of each possible sum of `dice` dice, whose results come in the given runs.
+///
+/// Every run adds a whole range of previous sums at once, which prefix sums of the distribution provide.
+/// All sums of at least `cap` are collected at the index `cap`.
+fn sum_distribution(runs: &[(usize, usize, f64)], dice: u16, cap: usize) -> Vec<f64> {
+    let die_probability: f64 = runs
+        .iter()
+        .map(|&(_, length, probability)| length as f64 * probability)
+        .sum();
+
+    let mut distribution = vec![0.0; cap + 1];
+    distribution[0] = 1.0;
+
+    for _ in 0..dice {
+        // The first `n` sums add up to `prefix_sums[n]`.
+        let mut prefix_sums = vec![0.0; cap + 2];
+        for (sum, &probability) in distribution.iter().enumerate() {
+            prefix_sums[sum + 1] = prefix_sums[sum] + probability;
+        }
+
+        let mut next = vec![0.0; cap + 1];
+        let mut below_cap = 0.0;
+        for (sum, entry) in next.iter_mut().enumerate().take(cap) {
+            for &(first, length, probability) in runs {
+                if sum < first {
+                    continue;
+                }
+
+                // Previous sums, which reach this sum with a result of the run.
+                let highest = sum - first;
+                let lowest = (sum + 1).saturating_sub(first + length);
+                *entry += probability * (prefix_sums[highest + 1] - prefix_sums[lowest]);
+            }
+            below_cap += *entry;
+        }
+
+        let total = prefix_sums[cap + 1] * die_probability;
+        next[cap] = (total - below_cap).max(0.0);
+        distribution = next;
+    }
+
+    distribution
+}
+
+/// Calculates the mean and variance of the sum of the `kept` highest or lowest
+/// of `dice` dice, whose faces have the given probabilities.
+///
+/// The sum of the highest dice counts, for every face, how many kept dice show at least that face.
+/// Going through the faces from the highest one, only the amount of dice showing at least
+/// the current face has to be tracked, together with the moments of the sum so far.
+fn kept_sum_moments(faces: &[f64], dice: u16, kept: u16, keep_highest: bool) -> (f64, f64) {
+    if !keep_highest {
+        // The lowest dice are the highest ones of the mirrored dice.
+        let top = faces.len();
+        let mut mirrored = vec![0.0; top + 1];
+        for (face, &probability) in faces.iter().enumerate() {
+            mirrored[top - face] = probability;
+        }
+
+        let (mean, variance) = kept_sum_moments(&mirrored, dice, kept, true);
+        return (kept as f64 * top as f64 - mean, variance);
+    }
+
+    let dice = dice as usize;
+    let kept = kept as usize;
+    let binomials = binomials(dice);
+
+    // Probability of the amount of dice showing at least the current face,
+    // and the first two moments of the sum restricted to that amount.
+    let mut probabilities = vec![0.0; dice + 1];
+    let mut first_moments = vec![0.0; dice + 1];
+    let mut second_moments = vec![0.0; dice + 1];
+    probabilities[0] = 1.0;
+
+    let mut at_most_face: f64 = faces.iter().sum();
+    for face in (1..faces.len()).rev() {
+        // Dice below every higher face show this face with the given chance.
+        let chance = match at_most_face > 0.0 {
+            true => (faces[face] / at_most_face).min(1.0),
+            false => 0.0,
+        };
+        at_most_face -= faces[face];
+
+        if chance > 0.0 {
+            let hits = powers(chance, dice);
+            let misses = powers(1.0 - chance, dice);
+
+            let mut next_probabilities = vec![0.0; dice + 1];
+            let mut next_first_moments = vec![0.0; dice + 1];
+            let mut next_second_moments = vec![0.0; dice + 1];
+            for counted in 0..=dice {
+                if probabilities[counted] == 0.0 {
+                    continue;
+                }
+
+                let remaining = dice - counted;
+                for count in 0..=remaining {
+                    let probability =
+                        binomials[remaining][count] * hits[count] * misses[remaining - count];
+                    next_probabilities[counted + count] += probabilities[counted] * probability;
+                    next_first_moments[counted + count] += first_moments[counted] * probability;
+                    next_second_moments[counted + count] += second_moments[counted] * probability;
+                }
+            }
+
+            probabilities = next_probabilities;
+            first_moments = next_first_moments;
+            second_moments = next_second_moments;
+        }
+
+        // Every kept die showing at least this face adds 1 to the sum.
+        for counted in 0..=dice {
+            let added = counted.min(kept) as f64;
+            second_moments[counted] +=
+                2.0 * added * first_moments[counted] + added * added * probabilities[counted];
+            first_moments[counted] += added * probabilities[counted];
+        }
+    }
+
+    let mean: f64 = first_moments.iter().sum();
+    let second_moment: f64 = second_moments.iter().sum();
+    (mean, (second_moment - mean * mean).max(0.0))
+}
+
+/// Calculates the probability of each possible sum of the `kept` highest or lowest
+/// of `dice` dice, whose faces have the given probabilities.
+///
+/// Goes through the faces starting with the first one to be kept and decides
+/// how many of the remaining dice show each face. Once all kept dice show a face, their sum is final.
+/// All sums of at least `cap` are collected at the index `cap`.
+fn kept_sum_distribution(
+    faces: &[f64],
+    dice: u16,
+    kept: u16,
+    keep_highest: bool,
+    cap: usize,
+) -> Vec<f64> {
+    let dice = dice as usize;
+    let kept = kept as usize;
+    let binomials = binomials(dice);
+
+    let mut distribution = vec![0.0; cap + 1];
+    if kept == 0 {
+        distribution[0] = 1.0;
+        return distribution;
+    }
+
+    let mut ordered_faces: Vec<usize> = (0..faces.len()).filter(|&f| faces[f] > 0.0).collect();
+    if keep_highest {
+        ordered_faces.reverse();
+    }
+
+    // Probability of the amount of kept dice, which already show a face, and their sum.
+    let mut states = vec![vec![0.0; cap + 1]; kept];
+    states[0][0] = 1.0;
+
+    let mut remaining_probability: f64 = faces.iter().sum();
+    for face in ordered_faces {
+        // Dice without a face yet show this face with the given chance.
+        let chance = (faces[face] / remaining_probability).min(1.0);
+        remaining_probability -= faces[face];
+
+        let hits = powers(chance, dice);
+        let misses = powers(1.0 - chance, dice);
+
+        let mut next = vec![vec![0.0; cap + 1]; kept];
+        for (rolled, sums) in states.iter().enumerate() {
+            let remaining = dice - rolled;
+            let still_kept = kept - rolled;
+            for (sum, &probability) in sums.iter().enumerate() {
+                if probability == 0.0 {
+                    continue;
+                }
+
+                // Fewer dice than still kept show this face.
+                let mut partial = 0.0;
+                for count in 0..still_kept {
+                    let count_probability =
+                        binomials[remaining][count] * hits[count] * misses[remaining - count];
+                    partial += count_probability;
+                    next[rolled + count][(sum + face * count).min(cap)] +=
+                        probability * count_probability;
+                }
+
+                // Enough dice show this face to complete the kept dice.
+                let final_sum = (sum + face * still_kept).min(cap);
+                distribution[final_sum] += probability * (1.0 - partial).max(0.0);
+            }
+        }
+
+        states = next;
+    }
+
+    distribution
+}
+
+/// Calculates the powers of `base` up to `n`.
+fn powers(base: f64, n: usize) -> Vec<f64> {
+    let mut powers = vec![1.0; n + 1];
+    for exponent in 1..=n {
+        powers[exponent] = powers[exponent - 1] * base;
+    }
+    powers
+}
+
+/// Calculates the binomial coefficients up to `n` over `n`.
+fn binomials(n: usize) -> Vec<Vec<f64>> {
+    let mut binomials = vec![vec![0.0; n + 1]; n + 1];
+    for row in 0..=n {
+        binomials[row][0] = 1.0;
+        for column in 1..=row {
+            binomials[row][column] = binomials[row - 1][column - 1] + binomials[row - 1][column];
+        }
+    }
+    binomials
+}
+
+/// Calculates the mean and variance of a distribution of values stored at their index.
+fn moments(distribution: &[f64]) -> (f64, f64) {
+    let mean: f64 = distribution
+        .iter()
+        .enumerate()
+        .map(|(value, probability)| value as f64 * probability)
+        .sum();
+    let variance = distribution
+        .iter()
+        .enumerate()
+        .map(|(value, probability)| (value as f64 - mean).powi(2) * probability)
+        .sum();
+    (mean, variance)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_min_and_max_result() {
+        let tests = [
+            (Roll::new(20, 1, 0), (1, 20)),
+            (Roll::new(20, 3, 5), (8, 65)),
+            (Roll::new(2, 1, 0), (1, 2)),
+            (Roll::new(2, 4, 0), (4, 8)),
+            (Roll::new(6, 2, -5), (-3, 7)),
+            (Roll::new_fate(4, 0), (-4, 4)),
+            (Roll::new_fate(4, 2), (-2, 6)),
+            (Roll::new(6, 4, 0).keep(KeepMode::Highest(3)), (3, 18)),
+            (Roll::new(20, 2, 1).keep(KeepMode::Lowest(1)), (2, 21)),
+            (Roll::new(6, 4, 0).keep(KeepMode::DropLowest(1)), (3, 18)),
+            (Roll::new(6, 5, 0).keep(KeepMode::DropHighest(2)), (3, 18)),
+            (Roll::new_fate(4, 0).keep(KeepMode::Highest(2)), (-2, 2)),
+            (Roll::new(6, 4, 0).exploding(), (4, 2424)),
+            (
+                Roll::new(6, 4, 0).exploding().keep(KeepMode::Highest(3)),
+                (3, 1818),
+            ),
+            (Roll::new(1, 2, 0).exploding(), (202, 202)),
+            (Roll::new(1000, 1, 0).exploding(), (1, 65535)),
+            (Roll::new(0, 3, 1), (1, 1)),
+        ];
+
+        for (roll, expected_output) in tests {
+            assert_eq!((roll.min_result(), roll.max_result()), expected_output);
+        }
+    }
+
+    #[test]
+    fn test_average_and_variance() {
+        let tests = [
+            (Roll::new(6, 1, 0), (3.5, 35.0 / 12.0)),
+            (Roll::new(6, 2, 0), (7.0, 70.0 / 12.0)),
+            (Roll::new(20, 1, -5), (5.5, 399.0 / 12.0)),
+            (Roll::new(2, 4, 1), (7.0, 1.0)),
+            (Roll::new_fate(4, 1), (1.0, 8.0 / 3.0)),
+            (
+                Roll::new(6, 4, 0).keep(KeepMode::Highest(3)),
+                (15869.0 / 1296.0, 13_612_487.0 / 1_679_616.0),
+            ),
+            (
+                Roll::new(6, 4, 0).keep(KeepMode::DropLowest(1)),
+                (15869.0 / 1296.0, 13_612_487.0 / 1_679_616.0),
+            ),
+            (
+                Roll::new(20, 2, 0).keep(KeepMode::Highest(1)),
+                (13.825, 22.194375),
+            ),
+            (
+                Roll::new(20, 2, 0).keep(KeepMode::Lowest(1)),
+                (7.175, 22.194375),
+            ),
+            (
+                Roll::new(6, 4, 0).keep(KeepMode::DropHighest(1)),
+                (21.0 - 15869.0 / 1296.0, 13_612_487.0 / 1_679_616.0),
+            ),
+            (Roll::new(6, 1, 0).exploding(), (4.2, 10.64)),
+            (Roll::new(2, 3, 1).exploding(), (10.0, 24.0)),
+        ];
+
+        for (roll, (average, variance)) in tests {
+            assert!((roll.average() - average).abs() < 1e-9);
+            assert!((roll.variance() - variance).abs() < 1e-9);
+            assert!((roll.standard_deviation() - variance.sqrt()).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn test_large_kept_pools() {
+        let roll = Roll::parse_roll("100d100kh50").unwrap();
+        assert!((roll.average() - 3762.540429).abs() < 1e-5);
+        assert!((roll.max_result() as f64 - roll.average()) > 3.0 * roll.standard_deviation());
+
+        let roll = Roll::parse_roll("100d100dl1").unwrap();
+        assert!(roll.average() > roll.keep(KeepMode::DropHighest(1)).average());
+
+        let roll = Roll::parse_roll("20d20kh10").unwrap();
+        assert!((roll.probability_of_at_least(1) - 1.0).abs() < 1e-9);
+        assert!(roll.probability_of_at_least(200) > 0.0);
+    }
+
+    #[test]
+    fn test_large_exploding_pools() {
+        let roll = Roll::parse_roll("100d100!").unwrap();
+        assert_eq!(roll.probability_of_at_least(200_000), 0.0);
+        assert!((roll.probability_of_at_least(100) - 1.0).abs() < 1e-9);
+
+        let probability = roll.probability_of_at_least(roll.average() as i32);
+        assert!(probability > 0.4 && probability < 0.6);
+
+        let roll = Roll::parse_roll("20d100!kh10").unwrap();
+        assert!(roll.average() > Roll::new(100, 10, 0).exploding().average());
+    }
+
+    #[test]
+    fn test_probability_of_at_least() {
+        let tests = [
+            (Roll::new(6, 1, 0), 4, 0.5),
+            (Roll::new(6, 2, 0), 12, 1.0 / 36.0),
+            (Roll::new(6, 2, 0), 2, 1.0),
+            (Roll::new(6, 2, 0), 13, 0.0),
+            (Roll::new(20, 1, -5), 11, 0.25),
+            (Roll::new_fate(1, 0), 0, 2.0 / 3.0),
+            (Roll::new_fate(4, 0), 4, 1.0 / 81.0),
+            (
+                Roll::new(20, 2, 0).keep(KeepMode::Highest(1)),
+                20,
+                39.0 / 400.0,
+            ),
+            (
+                Roll::new(20, 2, 0).keep(KeepMode::Lowest(1)),
+                20,
+                1.0 / 400.0,
+            ),
+            (
+                Roll::new(6, 4, 0).keep(KeepMode::Highest(3)),
+                18,
+                7.0 / 432.0,
+            ),
+            (Roll::new(6, 4, 0).keep(KeepMode::DropLowest(1)), 19, 0.0),
+            (Roll::new(6, 4, 0).keep(KeepMode::DropHighest(1)), 3, 1.0),
+            (Roll::new(6, 1, 0).exploding(), 7, 1.0 / 6.0),
+            (Roll::new(6, 1, 0).exploding(), 12, 1.0 / 36.0),
+            (Roll::new(6, 2, 0).exploding(), 13, 1.0 / 6.0),
+            (
+                Roll::new(6, 2, 0).exploding().keep(KeepMode::Highest(1)),
+                7,
+                11.0 / 36.0,
+            ),
+        ];
+
+        for (roll, target, expected_output) in tests {
+            let output = roll.probability_of_at_least(target);
+            assert!((output - expected_output).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn test_from_probability_target() {
+        let tests = [
+            (0.5, 7, 6, Ok(Roll::new(6, 2, 0))),
+            (0.9, 4, 6, Ok(Roll::new(6, 2, 0))),
+            (0.0, 1, 20, Ok(Roll::new(20, 1, 0))),
+            (0.5, 10, 7, Err(RollError::DieTypeInvalid)),
+        ];
+
+        for (probability, target, sides, expected_output) in tests {
+            let output = Roll::from_probability_target(probability, target, sides);
+            assert_eq!(output, expected_output);
+        }
+    }
+}
@@ -0,0 +1,259 @@
+use rand::Rng;
+
+use crate::{Roll, MAX_EXPLOSIONS};
+
+impl Roll {
+    /// **Rolls the dice and returns the total, including the modifier.**
+    ///
+    /// * Uses [`rand::thread_rng()`], see [`Roll::roll_with_rng()`] to provide an RNG yourself.
+    ///
+    /// # Examples
+    /// ```
+    /// use die_parser::Roll;
+    ///
+    /// let total = Roll::new(6, 2, 3).roll();
+    /// assert!((5..=15).contains(&total));
+    /// ```
+    pub fn roll(&self) -> i32 {
+        self.roll_with_rng(&mut rand::thread_rng())
+    }
+
+    /// **Rolls the dice using the given RNG and returns the total, including the modifier.**
+    ///
+    /// * The total saturates at the bounds of `i32`.
+    ///
+    /// # Examples
+    /// ```
+    /// use die_parser::Roll;
+    /// use rand::rngs::StdRng;
+    /// use rand::SeedableRng;
+    ///
+    /// let roll = Roll::new(20, 1, 0);
+    ///
+    /// // Generators with the same seed make the same rolls.
+    /// let total = roll.roll_with_rng(&mut StdRng::seed_from_u64(7));
+    /// assert_eq!(total, roll.roll_with_rng(&mut StdRng::seed_from_u64(7)));
+    /// ```
+    pub fn roll_with_rng<R: Rng + ?Sized>(&self, rng: &mut R) -> i32 {
+        self.roll_total_from(&self.roll_individual(rng))
+    }
+
+    /// **Rolls the dice using the given RNG and returns the result of every single die.**
+    ///
+    /// * The modifier is not applied, see [`Roll::roll_total_from()`] to do so.
+    /// * Exploding dice are rolled again while they show their highest face, up to 100 times.
+    ///     * Every result of a die is added to its entry.
+    /// * If only some dice are kept, only their results are returned, sorted in ascending order.
+    ///     * See [`Roll::kept_results()`].
+    /// * Fate dice return their faces 1 to 3, which [`Roll::roll_total_from()`] reads as -1, 0 and +1.
+    /// * Dice without sides always show 0.
+    ///
+    /// # Examples
+    /// ```
+    /// use die_parser::Roll;
+    ///
+    /// let results = Roll::new(6, 4, 2).roll_individual(&mut rand::thread_rng());
+    /// assert_eq!(results.len(), 4);
+    /// assert!(results.iter().all(|result| (1..=6).contains(result)));
+    /// ```
+    pub fn roll_individual<R: Rng + ?Sized>(&self, rng: &mut R) -> Vec<u16> {
+        let results = roll_dice(rng, self.number_of_dice, self.number_of_sides);
+        let results = if self.exploding {
+            self.explode(rng, results)
+        } else {
+            results
+        };
+
+        match self.keep_mode {
+            Some(_) => self.kept_results(&results),
+            None => results,
+        }
+    }
+
+    /// Rolls every die showing its highest face again and adds the new result.
+    fn explode<R: Rng + ?Sized>(&self, rng: &mut R, results: Vec<u16>) -> Vec<u16> {
+        results
+            .into_iter()
+            .map(|mut result| {
+                let mut last_result = result;
+                for _ in 0..MAX_EXPLOSIONS {
+                    if last_result != self.number_of_sides {
+                        break;
+                    }
+                    last_result = roll_die(rng, self.number_of_sides);
+                    result = result.saturating_add(last_result);
+                }
+                result
+            })
+            .collect()
+    }
+
+    /// **Sums the given results of single dice and adds the modifier.**
+    ///
+    /// * The total saturates at the bounds of `i32`.
+    /// * The faces 1 to 3 of Fate dice count as -1, 0 and +1.
+    ///
+    /// # Examples
+    /// ```
+    /// use die_parser::Roll;
+    ///
+    /// let roll = Roll::new(6, 4, 2);
+    /// let mut results = roll.roll_individual(&mut rand::thread_rng());
+    ///
+    /// // Drop the lowest die.
+    /// results.sort();
+    /// let total = roll.roll_total_from(&results[1..]);
+    /// assert!((5..=20).contains(&total));
+    /// ```
+    pub fn roll_total_from(&self, rolls: &[u16]) -> i32 {
+        let face_offset = self.die_kind.face_offset();
+
+        rolls.iter().fold(self.modifier, |total, &result| {
+            total.saturating_add(i32::from(result) + face_offset)
+        })
+    }
+}
+
+/// Rolls a single die with the given amount of sides.
+///
+/// A die without sides always shows 0.
+pub(crate) fn roll_die<R: Rng + ?Sized>(rng: &mut R, number_of_sides: u16) -> u16 {
+    match number_of_sides {
+        0 => 0,
+        sides => rng.gen_range(1..=sides),
+    }
+}
+
+/// Rolls the given amount of dice with the given amount of sides.
+pub(crate) fn roll_dice<R: Rng + ?Sized>(
+    rng: &mut R,
+    number_of_dice: u16,
+    number_of_sides: u16,
+) -> Vec<u16> {
+    (0..number_of_dice)
+        .map(|_| roll_die(rng, number_of_sides))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    use super::*;
+    use crate::KeepMode;
+
+    #[test]
+    fn test_roll() {
+        let tests = [
+            (Roll::new(6, 1, 0), 1..=6),
+            (Roll::new(6, 3, 0), 3..=18),
+            (Roll::new(20, 1, -5), -4..=15),
+            (Roll::new(1, 4, 2), 6..=6),
+            (Roll::new(0, 3, 1), 1..=1),
+            (Roll::new_fate(4, 0), -4..=4),
+            (Roll::new_fate(1, 3), 2..=4),
+        ];
+
+        for (roll, expected_range) in tests {
+            for _ in 0..100 {
+                assert!(expected_range.contains(&roll.roll()));
+            }
+        }
+    }
+
+    #[test]
+    fn test_roll_individual() {
+        let mut rng = rand::thread_rng();
+        let tests = [
+            Roll::new(6, 1, 3),
+            Roll::new(20, 5, -3),
+            Roll::new(100, 12, 0),
+        ];
+
+        for roll in tests {
+            let results = roll.roll_individual(&mut rng);
+            assert_eq!(results.len(), usize::from(roll.number_of_dice));
+            assert!(results
+                .iter()
+                .all(|result| (1..=roll.number_of_sides).contains(result)));
+        }
+    }
+
+    #[test]
+    fn test_roll_individual_exploding() {
+        let mut rng = rand::thread_rng();
+        let roll = Roll::new(2, 50, 0).exploding();
+
+        let results = roll.roll_individual(&mut rng);
+        assert_eq!(results.len(), 50);
+        // A die only stops exploding on a result below its highest face.
+        assert!(results.iter().all(|result| result % 2 == 1));
+
+        // Dice with a single side always explode, so they stop at the cap.
+        let roll = Roll::new(1, 3, 0).exploding();
+        assert_eq!(roll.roll_individual(&mut rng), vec![101, 101, 101]);
+    }
+
+    #[test]
+    fn test_roll_individual_keep() {
+        let mut rng = rand::thread_rng();
+        let tests = [
+            (Roll::new(6, 4, 0).keep(KeepMode::Highest(3)), 3),
+            (Roll::new(20, 2, 0).keep(KeepMode::Lowest(1)), 1),
+        ];
+
+        for (roll, expected_len) in tests {
+            for _ in 0..100 {
+                let results = roll.roll_individual(&mut rng);
+                assert_eq!(results.len(), expected_len);
+                assert!(results.windows(2).all(|w| w[0] <= w[1]));
+            }
+        }
+
+        // Dropping the lowest of 4 dice is the same as keeping the 3 highest.
+        let drop_lowest = Roll::parse_roll("4d6dl1").unwrap();
+        let keep_highest = Roll::parse_roll("4d6kh3").unwrap();
+        for seed in 0..10 {
+            assert_eq!(
+                drop_lowest.roll_individual(&mut StdRng::seed_from_u64(seed)),
+                keep_highest.roll_individual(&mut StdRng::seed_from_u64(seed))
+            );
+        }
+
+        // Keeping the 3 highest of 4d6 ranges from 3 to 18.
+        let roll = Roll::new(6, 4, 0).keep(KeepMode::Highest(3));
+        for _ in 0..100 {
+            assert!((3..=18).contains(&roll.roll_with_rng(&mut rng)));
+        }
+    }
+
+    #[test]
+    fn test_roll_total_from() {
+        let tests = [
+            ((Roll::new(6, 3, 0), vec![1, 2, 3]), 6),
+            ((Roll::new(6, 3, -10), vec![1, 2, 3]), -4),
+            ((Roll::new(6, 3, 5), vec![]), 5),
+            ((Roll::new(6, 1, i32::MAX), vec![6]), i32::MAX),
+        ];
+
+        for ((roll, rolls), expected_output) in tests {
+            assert_eq!(roll.roll_total_from(&rolls), expected_output);
+        }
+    }
+
+    #[test]
+    fn test_roll_with_rng() {
+        let roll = Roll::new(100, 5, 10);
+
+        for seed in 0..10 {
+            let mut rng = StdRng::seed_from_u64(seed);
+            let mut same_rng = StdRng::seed_from_u64(seed);
+
+            assert_eq!(
+                roll.roll_with_rng(&mut rng),
+                roll.roll_with_rng(&mut same_rng)
+            );
+        }
+    }
+}
@@ -0,0 +1,148 @@
+use nom::character::complete::{anychar, char};
+use nom::multi::separated_list1;
+use nom::sequence::tuple;
+
+use crate::{parse_numbers, Roll, RollError};
+
+/// An ALIEN RPG roll, made of a pool of base dice and a pool of stress dice.
+#[derive(Debug, PartialEq)]
+pub struct AlienRoll {
+    /// The base dice, marked with `s`.
+    pub base: Roll,
+    /// The stress dice, marked with `t`.
+    pub stress: Roll,
+}
+#[cfg(feature = "rand")]
+impl AlienRoll {
+    /// Rolls both pools and evaluates successes, panic and facehugger alerts.
+    pub fn roll<R: rand::Rng + ?Sized>(&self, rng: &mut R) -> AlienResult {
+        let base = crate::rolling::roll_dice(rng, self.base.number_of_dice, 6);
+        let stress = crate::rolling::roll_dice(rng, self.stress.number_of_dice, 6);
+
+        AlienResult::from_results(&base, &stress)
+    }
+}
+
+/// The outcome of an [AlienRoll].
+#[derive(Debug, PartialEq)]
+pub struct AlienResult {
+    /// How many dice of either pool showed a 6.
+    pub successes: u16,
+    /// Whether any stress die showed a 1.
+    pub panic: bool,
+    /// Whether any base die showed a 1.
+    pub facehugger: bool,
+}
+impl AlienResult {
+    /// Evaluates the results of the single dice of both pools.
+    pub fn from_results(base: &[u16], stress: &[u16]) -> Self {
+        let successes = base.iter().chain(stress).filter(|&&result| result == 6);
+
+        Self {
+            successes: successes.count() as u16,
+            panic: stress.contains(&1),
+            facehugger: base.contains(&1),
+        }
+    }
+}
+
+impl Roll {
+    /// **Tries to parse input as ALIEN RPG dice pools (e.g. `6s+2t`).**
+    ///
+    /// * Whitespaces are ignored.
+    /// * Base dice are marked with `s` and stress dice with `t`.
+    ///     * Either pool may be left out, but neither may be given twice.
+    /// * All dice are d6s.
+    /// * Enforces a limit of 100 dice per pool.
+    ///
+    /// # Examples
+    /// ```
+    /// use die_parser::Roll;
+    ///
+    /// let alien_roll = Roll::parse_roll_from_alien_rpg("6s+2t").unwrap();
+    /// assert_eq!(alien_roll.base, Roll::new(6, 6, 0));
+    /// assert_eq!(alien_roll.stress, Roll::new(6, 2, 0));
+    /// ```
+    pub fn parse_roll_from_alien_rpg(input: &str) -> Result<AlienRoll, RollError> {
+        // Remove whitespaces.
+        let whitespaceless = input.replace(' ', "");
+
+        let pools =
+            match separated_list1(char('+'), tuple((parse_numbers, anychar)))(&whitespaceless) {
+                Ok(("", pools)) => pools,
+                _ => return Err(RollError::ParsingError),
+            };
+
+        let mut base = None;
+        let mut stress = None;
+
+        for (number_of_dice, pool_type) in pools {
+            let pool = Roll::new(6, number_of_dice, 0);
+            pool.check_roll_validity(100)?;
+
+            let slot = match pool_type {
+                's' => &mut base,
+                't' => &mut stress,
+                _ => return Err(RollError::ParsingError),
+            };
+            if slot.replace(pool).is_some() {
+                return Err(RollError::ParsingError);
+            }
+        }
+
+        Ok(AlienRoll {
+            base: base.unwrap_or(Roll::new(6, 0, 0)),
+            stress: stress.unwrap_or(Roll::new(6, 0, 0)),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_roll_from_alien_rpg() {
+        let tests = [
+            ("6s+2t", Ok((6, 2))),
+            ("2t + 6s", Ok((6, 2))),
+            ("4s", Ok((4, 0))),
+            ("6s+2t+1t", Err(RollError::ParsingError)),
+            ("6s+2x", Err(RollError::ParsingError)),
+            ("6d6s", Err(RollError::ParsingError)),
+            ("101s", Err(RollError::DiceExceedLimit)),
+        ];
+
+        for (input, expected_output) in tests {
+            let output = Roll::parse_roll_from_alien_rpg(input)
+                .map(|roll| (roll.base.number_of_dice, roll.stress.number_of_dice));
+            assert_eq!(output, expected_output);
+        }
+    }
+
+    #[test]
+    fn test_from_results() {
+        let tests = [
+            (
+                (vec![6, 3, 1], vec![6, 2]),
+                AlienResult {
+                    successes: 2,
+                    panic: false,
+                    facehugger: true,
+                },
+            ),
+            (
+                (vec![4, 5], vec![1]),
+                AlienResult {
+                    successes: 0,
+                    panic: true,
+                    facehugger: false,
+                },
+            ),
+        ];
+
+        for ((base, stress), expected_output) in tests {
+            assert_eq!(AlienResult::from_results(&base, &stress), expected_output);
+        }
+    }
+}
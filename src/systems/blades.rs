@@ -0,0 +1,106 @@
+use crate::{Roll, RollError};
+
+/// A Blades in the Dark dice pool, of which only the highest die counts.
+#[derive(Debug, PartialEq)]
+pub struct BladesRoll {
+    /// The pool of d6s to be rolled.
+    pub pool: Roll,
+}
+#[cfg(feature = "rand")]
+impl BladesRoll {
+    /// Rolls the pool and determines the outcome of the highest die.
+    pub fn roll<R: rand::Rng + ?Sized>(&self, rng: &mut R) -> BladesResult {
+        let results = crate::rolling::roll_dice(rng, self.pool.number_of_dice, 6);
+
+        BladesResult::from_results(&results)
+    }
+}
+
+/// The outcome of a [BladesRoll].
+#[derive(Debug, PartialEq)]
+pub enum BladesResult {
+    /// The highest die showed 1 to 3.
+    Bad,
+    /// The highest die showed 4 or 5.
+    Partial,
+    /// The highest die showed a 6.
+    Success,
+    /// More than one die showed a 6.
+    Critical,
+}
+impl BladesResult {
+    /// Evaluates the results of the single dice of a pool.
+    pub fn from_results(results: &[u16]) -> Self {
+        let sixes = results.iter().filter(|&&result| result == 6).count();
+
+        match (sixes, results.iter().max()) {
+            (2.., _) => Self::Critical,
+            (1, _) => Self::Success,
+            (_, Some(4..=5)) => Self::Partial,
+            _ => Self::Bad,
+        }
+    }
+}
+
+impl Roll {
+    /// **Tries to parse input as a Blades in the Dark dice pool (e.g. `3d` or `3d6`).**
+    ///
+    /// * Whitespaces are ignored.
+    /// * Only d6s without a modifier are allowed, so the type of die may be omitted.
+    /// * Enforces a limit of 100 dice per pool.
+    ///
+    /// # Examples
+    /// ```
+    /// use die_parser::Roll;
+    ///
+    /// let blades_roll = Roll::parse_roll_from_blades_notation("3d").unwrap();
+    /// assert_eq!(blades_roll.pool, Roll::new(6, 3, 0));
+    /// ```
+    pub fn parse_roll_from_blades_notation(input: &str) -> Result<BladesRoll, RollError> {
+        // Blades shares the implicit d6 with GURPS.
+        let pool = Roll::parse_roll_from_gurps_notation(input)?;
+
+        if pool.number_of_sides != 6 {
+            return Err(RollError::DieTypeInvalid);
+        } else if pool.modifier != 0 {
+            return Err(RollError::ParsingError);
+        }
+
+        Ok(BladesRoll { pool })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_roll_from_blades_notation() {
+        let tests = [
+            ("3d", Ok(Roll::new(6, 3, 0))),
+            ("3d6", Ok(Roll::new(6, 3, 0))),
+            ("3d+1", Err(RollError::ParsingError)),
+            ("3d8", Err(RollError::DieTypeInvalid)),
+            ("0d", Err(RollError::NoDiceToRoll)),
+        ];
+
+        for (input, expected_output) in tests {
+            let output = Roll::parse_roll_from_blades_notation(input).map(|roll| roll.pool);
+            assert_eq!(output, expected_output);
+        }
+    }
+
+    #[test]
+    fn test_blades_result() {
+        let tests = [
+            (vec![1, 3, 2], BladesResult::Bad),
+            (vec![1, 5, 4], BladesResult::Partial),
+            (vec![6, 5, 4], BladesResult::Success),
+            (vec![6, 1, 6], BladesResult::Critical),
+        ];
+
+        for (results, expected_output) in tests {
+            assert_eq!(BladesResult::from_results(&results), expected_output);
+        }
+    }
+}
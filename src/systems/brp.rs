@@ -0,0 +1,110 @@
+use crate::{Roll, RollError};
+
+/// A Basic Roleplaying skill roll, rolling a d100 under the skill.
+#[derive(Debug, PartialEq)]
+pub struct BrpRoll {
+    /// The parsed roll, which is always `1d100`.
+    pub roll: Roll,
+    /// The skill the roll has to be equal to or lower than.
+    pub skill: u16,
+}
+impl BrpRoll {
+    /// Determines the success tier of a rolled result against a skill.
+    ///
+    /// Thresholds are rounded down.
+    ///
+    /// | Result      | Outcome                  |
+    /// |-------------|--------------------------|
+    /// | ≤ skill / 5 | [`BrpResult::Critical`]  |
+    /// | ≤ skill / 2 | [`BrpResult::Special`]   |
+    /// | ≤ skill     | [`BrpResult::Success`]   |
+    /// | > skill     | [`BrpResult::Failure`]   |
+    pub fn result(roll: u16, skill: u16) -> BrpResult {
+        if roll <= skill / 5 {
+            BrpResult::Critical
+        } else if roll <= skill / 2 {
+            BrpResult::Special
+        } else if roll <= skill {
+            BrpResult::Success
+        } else {
+            BrpResult::Failure
+        }
+    }
+}
+
+/// The success tier of a [BrpRoll].
+#[derive(Debug, PartialEq)]
+pub enum BrpResult {
+    /// A roll of at most a fifth of the skill.
+    Critical,
+    /// A roll of at most half the skill.
+    Special,
+    /// A roll at or below the skill.
+    Success,
+    /// A roll above the skill.
+    Failure,
+}
+
+impl Roll {
+    /// **Tries to parse input as a Basic Roleplaying skill roll (e.g. `1d100<=45`).**
+    ///
+    /// * Whitespaces are ignored.
+    /// * The roll has to be exactly `1d100`.
+    ///
+    /// # Examples
+    /// ```
+    /// use die_parser::Roll;
+    /// use die_parser::systems::{BrpResult, BrpRoll};
+    ///
+    /// let brp_roll = Roll::parse_roll_from_brp_notation("1d100<=45").unwrap();
+    /// assert_eq!(brp_roll.skill, 45);
+    /// assert_eq!(BrpRoll::result(9, brp_roll.skill), BrpResult::Critical);
+    /// ```
+    pub fn parse_roll_from_brp_notation(input: &str) -> Result<BrpRoll, RollError> {
+        let (roll, skill) = super::parse_roll_under(input, 100)?;
+
+        if roll.number_of_dice != 1 {
+            return Err(RollError::ParsingError);
+        }
+
+        Ok(BrpRoll { roll, skill })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_roll_from_brp_notation() {
+        let tests = [
+            ("1d100<=45", Ok(45)),
+            ("d100 <= 70", Ok(70)),
+            ("1d100", Err(RollError::ParsingError)),
+            ("2d100<=45", Err(RollError::ParsingError)),
+            ("1d20<=45", Err(RollError::DieTypeInvalid)),
+        ];
+
+        for (input, expected_output) in tests {
+            let output = Roll::parse_roll_from_brp_notation(input).map(|roll| roll.skill);
+            assert_eq!(output, expected_output);
+        }
+    }
+
+    #[test]
+    fn test_result() {
+        let tests = [
+            (1, BrpResult::Critical),
+            (9, BrpResult::Critical),
+            (10, BrpResult::Special),
+            (22, BrpResult::Special),
+            (23, BrpResult::Success),
+            (45, BrpResult::Success),
+            (46, BrpResult::Failure),
+        ];
+
+        for (roll, expected_output) in tests {
+            assert_eq!(BrpRoll::result(roll, 45), expected_output);
+        }
+    }
+}
@@ -0,0 +1,171 @@
+use crate::{parse_numbers, Roll, RollError};
+
+/// The most times a single open-ended die may be rerolled.
+#[cfg(feature = "rand")]
+const MAX_REROLLS: u16 = 100;
+
+/// A Burning Wheel dice pool of a given shade.
+#[derive(Debug, PartialEq)]
+pub struct BwRoll {
+    /// The pool of d6s to be rolled.
+    pub pool: Roll,
+    /// The shade of the pool, deciding which results succeed.
+    pub shade: BwShade,
+    /// Whether every 6 is rerolled and may add another success.
+    pub open_ended: bool,
+}
+impl BwRoll {
+    /// Counts the successes among the results of the single dice of the pool, including rerolls.
+    pub fn count_successes(&self, results: &[u16]) -> u16 {
+        let target = self.shade.target();
+        results.iter().filter(|&&result| result >= target).count() as u16
+    }
+}
+#[cfg(feature = "rand")]
+impl BwRoll {
+    /// Rolls the pool and counts successes, rerolling every 6 if the pool is open-ended.
+    pub fn roll<R: rand::Rng + ?Sized>(&self, rng: &mut R) -> u16 {
+        let mut results = crate::rolling::roll_dice(rng, self.pool.number_of_dice, 6);
+
+        if self.open_ended {
+            let sixes = results.iter().filter(|&&result| result == 6).count();
+
+            for _ in 0..sixes {
+                let mut rerolls = 0;
+                loop {
+                    let result = crate::rolling::roll_die(rng, 6);
+                    results.push(result);
+
+                    rerolls += 1;
+                    if result != 6 || rerolls >= MAX_REROLLS {
+                        break;
+                    }
+                }
+            }
+        }
+
+        self.count_successes(&results)
+    }
+}
+
+/// The shade of a [BwRoll].
+#[derive(Debug, PartialEq)]
+pub enum BwShade {
+    /// Succeeds on 4 to 6, marked with `B`.
+    Black,
+    /// Succeeds on 3 to 6, marked with `G`.
+    Grey,
+    /// Succeeds on 2 to 6, marked with `W`.
+    White,
+}
+impl BwShade {
+    /// The lowest result that counts as a success.
+    fn target(&self) -> u16 {
+        match self {
+            Self::Black => 4,
+            Self::Grey => 3,
+            Self::White => 2,
+        }
+    }
+}
+
+impl Roll {
+    /// **Tries to parse input as a Burning Wheel dice pool (e.g. `B4`, `G3!` or `4d6`).**
+    ///
+    /// * Whitespaces are ignored.
+    /// * The shade is marked with `B`, `G` or `W` before the pool size.
+    ///     * Plain d6 notation is read as a black shade pool.
+    /// * A `!` suffix makes the pool open-ended.
+    /// * Enforces a limit of 100 dice per pool.
+    ///
+    /// # Examples
+    /// ```
+    /// use die_parser::Roll;
+    /// use die_parser::systems::BwShade;
+    ///
+    /// let bw_roll = Roll::parse_roll_from_burning_wheel("G4!").unwrap();
+    /// assert_eq!(bw_roll.pool, Roll::new(6, 4, 0));
+    /// assert_eq!(bw_roll.shade, BwShade::Grey);
+    /// assert!(bw_roll.open_ended);
+    /// ```
+    pub fn parse_roll_from_burning_wheel(input: &str) -> Result<BwRoll, RollError> {
+        let whitespaceless = input.replace(' ', "");
+
+        let (notation, open_ended) = match whitespaceless.strip_suffix('!') {
+            Some(notation) => (notation, true),
+            None => (whitespaceless.as_str(), false),
+        };
+
+        let mut shade = BwShade::Black;
+        let pool = match notation.get(..1).zip(notation.get(1..)) {
+            Some((marker @ ("B" | "G" | "W"), exponent)) => {
+                shade = match marker {
+                    "G" => BwShade::Grey,
+                    "W" => BwShade::White,
+                    _ => BwShade::Black,
+                };
+
+                let number_of_dice = match parse_numbers(exponent) {
+                    Ok(("", number_of_dice)) => number_of_dice,
+                    _ => return Err(RollError::ParsingError),
+                };
+                let pool = Roll::new(6, number_of_dice, 0);
+                pool.check_roll_validity(100)?;
+                pool
+            }
+            _ => super::parse_pool(notation, 6)?,
+        };
+
+        Ok(BwRoll {
+            pool,
+            shade,
+            open_ended,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_roll_from_burning_wheel() {
+        let tests = [
+            ("B4", Ok((4, BwShade::Black, false))),
+            ("G3!", Ok((3, BwShade::Grey, true))),
+            ("W 5", Ok((5, BwShade::White, false))),
+            ("4d6!", Ok((4, BwShade::Black, true))),
+            ("X4", Err(RollError::ParsingError)),
+            ("B4d6", Err(RollError::ParsingError)),
+            ("B0", Err(RollError::NoDiceToRoll)),
+            ("4d8", Err(RollError::DieTypeInvalid)),
+        ];
+
+        for (input, expected_output) in tests {
+            let output = Roll::parse_roll_from_burning_wheel(input)
+                .map(|roll| (roll.pool.number_of_dice, roll.shade, roll.open_ended));
+            assert_eq!(output, expected_output);
+        }
+    }
+
+    #[test]
+    fn test_count_successes() {
+        let tests = [("B4", 2), ("G4", 3), ("W4", 4)];
+
+        for (input, expected_output) in tests {
+            let bw_roll = Roll::parse_roll_from_burning_wheel(input).unwrap();
+            assert_eq!(bw_roll.count_successes(&[2, 3, 4, 6]), expected_output);
+        }
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn test_roll() {
+        let mut rng = rand::thread_rng();
+        let bw_roll = Roll::parse_roll_from_burning_wheel("W4").unwrap();
+
+        for _ in 0..100 {
+            assert!(bw_roll.roll(&mut rng) <= 4);
+        }
+    }
+}
@@ -0,0 +1,244 @@
+use crate::{Roll, RollError};
+
+/// The types of die an impaired or enhanced roll steps through, from smallest to largest.
+const STEP_CHAIN: [u16; 5] = [4, 6, 8, 10, 12];
+
+/// The types of die a hit die is exhausted through, from largest to smallest.
+const HIT_DIE_CHAIN: [u16; 5] = [12, 10, 8, 6, 4];
+
+/// A Cairn roll that may be impaired or enhanced.
+#[derive(Debug, PartialEq)]
+pub struct CairnRoll {
+    /// The parsed roll, before impairing or enhancing it.
+    pub roll: Roll,
+    /// How many steps the die is moved: `-1` if impaired, `1` if enhanced, `2` if doubly enhanced.
+    pub level: i8,
+}
+impl CairnRoll {
+    /// Returns the roll with its die stepped down when impaired, or up when enhanced.
+    ///
+    /// The die can't step below a d4 or above a d12.
+    pub fn effective_roll(&self) -> Roll {
+        let position = STEP_CHAIN
+            .iter()
+            .position(|&sides| sides == self.roll.number_of_sides)
+            .unwrap_or(1) as i32;
+        let stepped = (position + self.level as i32).clamp(0, STEP_CHAIN.len() as i32 - 1);
+
+        Roll::new(
+            STEP_CHAIN[stepped as usize],
+            self.roll.number_of_dice,
+            self.roll.modifier,
+        )
+    }
+}
+/// A Cairn hit die, which is exhausted to the next smaller die until it is depleted.
+#[derive(Debug, PartialEq)]
+pub struct CairnHd {
+    /// The die currently used.
+    pub die: Roll,
+    /// Whether the hit die has been exhausted past a d4.
+    pub depleted: bool,
+}
+impl CairnHd {
+    /// Returns the next smaller hit die, or `None` if a d4 is exhausted or the hit die is depleted.
+    pub fn exhaust(&self) -> Option<Roll> {
+        if self.depleted {
+            return None;
+        }
+
+        super::next_die_in_chain(&HIT_DIE_CHAIN, &self.die)
+    }
+
+    /// Returns the hit die after exhausting it once.
+    ///
+    /// Exhausting a d4 depletes the hit die, which then stays depleted.
+    pub fn exhausted(&self) -> CairnHd {
+        match self.exhaust() {
+            Some(die) => CairnHd {
+                die,
+                depleted: false,
+            },
+            None => CairnHd {
+                die: self.die,
+                depleted: true,
+            },
+        }
+    }
+}
+
+#[cfg(feature = "rand")]
+impl CairnRoll {
+    /// Rolls the effective dice and adds the modifier.
+    pub fn roll<R: rand::Rng + ?Sized>(&self, rng: &mut R) -> i32 {
+        let roll = self.effective_roll();
+        let results = crate::rolling::roll_dice(rng, roll.number_of_dice, roll.number_of_sides);
+
+        roll.roll_total_from(&results)
+    }
+}
+
+impl Roll {
+    /// **Tries to parse input as Cairn roll notation (e.g. `1d6!i` or `1d8!e2+1`).**
+    ///
+    /// * Whitespaces are ignored.
+    /// * An optional suffix after the die changes its type:
+    ///     * `!i`: Impaired, one step down.
+    ///     * `!e`: Enhanced, one step up.
+    ///     * `!e2`: Doubly enhanced, two steps up.
+    /// * Valid die types are: d4, d6, d8, d10, d12
+    /// * Enforces a limit of 100 dice per roll.
+    /// * Keeping or dropping dice and Fate dice return [`RollError::ParsingError`].
+    ///
+    /// # Examples
+    /// ```
+    /// use die_parser::Roll;
+    ///
+    /// let cairn_roll = Roll::parse_roll_from_cairn_notation("1d6!i").unwrap();
+    /// assert_eq!(cairn_roll.level, -1);
+    /// assert_eq!(cairn_roll.effective_roll(), Roll::new(4, 1, 0));
+    /// ```
+    pub fn parse_roll_from_cairn_notation(input: &str) -> Result<CairnRoll, RollError> {
+        let whitespaceless = input.replace(' ', "");
+
+        // Split the suffix from the roll, keeping the modifier.
+        let (notation, level) = match whitespaceless.split_once('!') {
+            Some((dice, suffix)) => {
+                let (level, modifier) = if let Some(modifier) = suffix.strip_prefix("e2") {
+                    (2, modifier)
+                } else if let Some(modifier) = suffix.strip_prefix('e') {
+                    (1, modifier)
+                } else if let Some(modifier) = suffix.strip_prefix('i') {
+                    (-1, modifier)
+                } else {
+                    return Err(RollError::ParsingError);
+                };
+                (format!("{dice}{modifier}"), level)
+            }
+            None => (whitespaceless, 0),
+        };
+
+        let roll = super::plain_roll(Roll::parse_modified_roll(&notation)?)?;
+        roll.check_roll_validity_with_die_types(100, &STEP_CHAIN)?;
+
+        Ok(CairnRoll { roll, level })
+    }
+
+    /// **Tries to parse input as a Cairn hit die (e.g. `d10hd`).**
+    ///
+    /// * Whitespaces are ignored.
+    /// * The die has to be suffixed with `hd` and may be written with or without `1`.
+    /// * Valid hit dice are: d4, d6, d8, d10, d12
+    ///
+    /// # Examples
+    /// ```
+    /// use die_parser::Roll;
+    ///
+    /// let hit_die = Roll::parse_roll_from_cairn_hd("d10hd").unwrap();
+    /// assert_eq!(hit_die.die, Roll::new(10, 1, 0));
+    /// assert_eq!(hit_die.exhaust(), Some(Roll::new(8, 1, 0)));
+    ///
+    /// let d4 = Roll::parse_roll_from_cairn_hd("d4hd").unwrap();
+    /// assert!(d4.exhausted().depleted);
+    /// ```
+    pub fn parse_roll_from_cairn_hd(input: &str) -> Result<CairnHd, RollError> {
+        let die = super::parse_suffixed_die(input, "hd", &HIT_DIE_CHAIN)?;
+
+        Ok(CairnHd {
+            die,
+            depleted: false,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_roll_from_cairn_notation() {
+        let tests = [
+            ("1d6", Ok((Roll::new(6, 1, 0), 0))),
+            ("1d6!i", Ok((Roll::new(6, 1, 0), -1))),
+            ("1d8!e", Ok((Roll::new(8, 1, 0), 1))),
+            ("2d8!e2+1", Ok((Roll::new(8, 2, 1), 2))),
+            ("1d6!x", Err(RollError::ParsingError)),
+            ("1d20!e", Err(RollError::DieTypeInvalid)),
+            ("3d6kh1!e", Err(RollError::ParsingError)),
+            ("2d8dl1", Err(RollError::ParsingError)),
+            ("4dF", Err(RollError::ParsingError)),
+        ];
+
+        for (input, expected_output) in tests {
+            let output = Roll::parse_roll_from_cairn_notation(input)
+                .map(|cairn_roll| (cairn_roll.roll, cairn_roll.level));
+            assert_eq!(output, expected_output);
+        }
+    }
+
+    #[test]
+    fn test_effective_roll() {
+        let tests = [
+            ("1d6", Roll::new(6, 1, 0)),
+            ("1d6!i", Roll::new(4, 1, 0)),
+            ("1d4!i", Roll::new(4, 1, 0)),
+            ("1d6!e", Roll::new(8, 1, 0)),
+            ("2d8!e2+1", Roll::new(12, 2, 1)),
+            ("1d12!e", Roll::new(12, 1, 0)),
+        ];
+
+        for (input, expected_output) in tests {
+            let cairn_roll = Roll::parse_roll_from_cairn_notation(input).unwrap();
+            assert_eq!(cairn_roll.effective_roll(), expected_output);
+        }
+    }
+
+    #[test]
+    fn test_parse_roll_from_cairn_hd() {
+        let tests = [
+            ("d10hd", Ok(Roll::new(10, 1, 0))),
+            ("1d12hd", Ok(Roll::new(12, 1, 0))),
+            ("d10", Err(RollError::ParsingError)),
+            ("2d6hd", Err(RollError::ParsingError)),
+            ("d20hd", Err(RollError::DieTypeInvalid)),
+        ];
+
+        for (input, expected_output) in tests {
+            let output = Roll::parse_roll_from_cairn_hd(input).map(|hit_die| hit_die.die);
+            assert_eq!(output, expected_output);
+        }
+    }
+
+    #[test]
+    fn test_exhaust() {
+        let tests = [
+            ((12, false), Some(Roll::new(10, 1, 0))),
+            ((6, false), Some(Roll::new(4, 1, 0))),
+            ((4, false), None),
+            ((8, true), None),
+        ];
+
+        for ((sides, depleted), expected_output) in tests {
+            let hit_die = CairnHd {
+                die: Roll::new(sides, 1, 0),
+                depleted,
+            };
+            assert_eq!(hit_die.exhaust(), expected_output);
+        }
+    }
+
+    #[test]
+    fn test_exhausted() {
+        let mut hit_die = Roll::parse_roll_from_cairn_hd("d8hd").unwrap();
+        let mut dice = Vec::new();
+
+        while !hit_die.depleted {
+            dice.push(hit_die.die.number_of_sides);
+            hit_die = hit_die.exhausted();
+        }
+
+        assert_eq!(dice, vec![8, 6, 4]);
+        assert_eq!(hit_die.die, Roll::new(4, 1, 0));
+        assert_eq!(hit_die.exhausted(), hit_die);
+    }
+}
@@ -0,0 +1,76 @@
+use crate::{Roll, RollError};
+
+/// The types of die used by Call of Cthulhu.
+const COC_DIE_TYPES: [u16; 8] = [3, 4, 6, 8, 10, 12, 20, 100];
+
+/// A Call of Cthulhu roll.
+#[derive(Debug, PartialEq)]
+pub struct CocRoll {
+    /// The parsed roll.
+    pub roll: Roll,
+    /// Whether the roll uses d10s, whose face showing `0` is recorded as `10`.
+    pub tens_die: bool,
+}
+
+impl Roll {
+    /// **Tries to parse input as Call of Cthulhu roll notation (e.g. `d%` or `1d3+1`).**
+    ///
+    /// * Whitespaces are ignored.
+    /// * `d%` is read as `1d100`.
+    /// * A missing amount of dice is read as a single die (e.g. `d10`).
+    /// * Checks for validity of roll.[^1]
+    ///     * Enforces a limit of 100 dice per roll.
+    ///
+    /// # Examples
+    /// ```
+    /// use die_parser::Roll;
+    ///
+    /// let coc_roll = Roll::parse_roll_from_call_of_cthulhu("d%").unwrap();
+    /// assert_eq!(coc_roll.roll, Roll::new(100, 1, 0));
+    ///
+    /// let coc_roll = Roll::parse_roll_from_call_of_cthulhu("1d10").unwrap();
+    /// assert!(coc_roll.tens_die);
+    /// ```
+    /// [^1]: Valid die types are: d3, d4, d6, d8, d10, d12, d20, d100
+    pub fn parse_roll_from_call_of_cthulhu(input: &str) -> Result<CocRoll, RollError> {
+        let mut notation = input.replace(' ', "").replace("d%", "d100");
+
+        // A missing amount of dice means a single die.
+        if notation.starts_with('d') {
+            notation.insert(0, '1');
+        }
+
+        let roll = Roll::parse_modified_roll(&notation)?;
+        roll.check_roll_validity_with_die_types(100, &COC_DIE_TYPES)?;
+
+        Ok(CocRoll {
+            tens_die: roll.number_of_sides == 10,
+            roll,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_roll_from_call_of_cthulhu() {
+        let tests = [
+            ("d%", Ok((Roll::new(100, 1, 0), false))),
+            ("d% + 5", Ok((Roll::new(100, 1, 5), false))),
+            ("1d10", Ok((Roll::new(10, 1, 0), true))),
+            ("d3", Ok((Roll::new(3, 1, 0), false))),
+            ("2d6+6", Ok((Roll::new(6, 2, 6), false))),
+            ("1d2", Err(RollError::DieTypeInvalid)),
+            ("4dF", Err(RollError::DieTypeInvalid)),
+            ("%", Err(RollError::ParsingError)),
+        ];
+
+        for (input, expected_output) in tests {
+            let output = Roll::parse_roll_from_call_of_cthulhu(input)
+                .map(|coc_roll| (coc_roll.roll, coc_roll.tens_die));
+            assert_eq!(output, expected_output);
+        }
+    }
+}
@@ -0,0 +1,96 @@
+use crate::{Roll, RollError};
+
+/// A Conan (2d20 system) test, rolling d20s under an attribute.
+#[derive(Debug, PartialEq)]
+pub struct Conan2d20 {
+    /// The pool of d20s to be rolled.
+    pub pool: Roll,
+    /// The attribute each die has to be equal to or lower than.
+    pub attribute: u8,
+}
+impl Conan2d20 {
+    /// Counts the successes of the single dice of a pool.
+    ///
+    /// Every die at or below the attribute is a success, and every 1 counts as an extra success.
+    pub fn count_successes(results: &[u16], attribute: u8) -> u8 {
+        results
+            .iter()
+            .map(|&result| match result {
+                1 => 2,
+                _ if result <= u16::from(attribute) => 1,
+                _ => 0,
+            })
+            .sum()
+    }
+}
+#[cfg(feature = "rand")]
+impl Conan2d20 {
+    /// Rolls the pool and counts successes against an attribute.
+    pub fn roll<R: rand::Rng + ?Sized>(&self, rng: &mut R, attribute: u8) -> u8 {
+        let results = crate::rolling::roll_dice(rng, self.pool.number_of_dice, 20);
+
+        Self::count_successes(&results, attribute)
+    }
+}
+
+impl Roll {
+    /// **Tries to parse input as a Conan (2d20 system) test (e.g. `2d20<=12`).**
+    ///
+    /// * Whitespaces are ignored.
+    /// * Only d20s without a modifier are allowed.
+    /// * Enforces a limit of 5 dice per pool.
+    ///
+    /// # Examples
+    /// ```
+    /// use die_parser::Roll;
+    ///
+    /// let conan_roll = Roll::parse_roll_from_conan2d20("2d20<=12").unwrap();
+    /// assert_eq!(conan_roll.pool, Roll::new(20, 2, 0));
+    /// assert_eq!(conan_roll.attribute, 12);
+    /// ```
+    pub fn parse_roll_from_conan2d20(input: &str) -> Result<Conan2d20, RollError> {
+        let (pool, attribute) = super::parse_roll_under(input, 20)?;
+        pool.check_roll_validity(5)?;
+
+        let attribute = u8::try_from(attribute).map_err(|_| RollError::ParsingError)?;
+
+        Ok(Conan2d20 { pool, attribute })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_roll_from_conan2d20() {
+        let tests = [
+            ("2d20<=12", Ok((2, 12))),
+            ("5d20 <= 9", Ok((5, 9))),
+            ("6d20<=12", Err(RollError::DiceExceedLimit)),
+            ("2d20<=300", Err(RollError::ParsingError)),
+            ("2d20", Err(RollError::ParsingError)),
+            ("2d12<=12", Err(RollError::DieTypeInvalid)),
+        ];
+
+        for (input, expected_output) in tests {
+            let output = Roll::parse_roll_from_conan2d20(input)
+                .map(|roll| (roll.pool.number_of_dice, roll.attribute));
+            assert_eq!(output, expected_output);
+        }
+    }
+
+    #[test]
+    fn test_count_successes() {
+        let tests = [
+            (vec![1, 20], 2),
+            (vec![12, 5], 2),
+            (vec![13, 20], 0),
+            (vec![1, 1, 3], 5),
+        ];
+
+        for (results, expected_output) in tests {
+            assert_eq!(Conan2d20::count_successes(&results, 12), expected_output);
+        }
+    }
+}
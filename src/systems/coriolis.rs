@@ -0,0 +1,122 @@
+use crate::{Roll, RollError};
+
+/// A Coriolis dice pool, in which every 6 is a success.
+#[derive(Debug, PartialEq)]
+pub struct CoriolisRoll {
+    /// The pool of d6s to be rolled.
+    pub pool: Roll,
+}
+#[cfg(feature = "rand")]
+impl CoriolisRoll {
+    /// Rolls the pool and counts successes.
+    pub fn roll<R: rand::Rng + ?Sized>(&self, rng: &mut R) -> CoriolisResult {
+        self.roll_with_push(rng, false)
+    }
+
+    /// Rolls the pool and counts successes, rerolling all non-sixes once if `push` is requested.
+    ///
+    /// A push is a prayer to the Icons, so it only happens if at least one die can be rerolled.
+    pub fn roll_with_push<R: rand::Rng + ?Sized>(&self, rng: &mut R, push: bool) -> CoriolisResult {
+        let results = crate::rolling::roll_dice(rng, self.pool.number_of_dice, 6);
+        let mut result = CoriolisResult::from_results(&results);
+
+        let rerolls = self.pool.number_of_dice - result.successes;
+        if push && rerolls > 0 {
+            let rerolled = crate::rolling::roll_dice(rng, rerolls, 6);
+
+            result.successes += CoriolisResult::from_results(&rerolled).successes;
+            result.pushed = true;
+        }
+
+        result
+    }
+}
+
+/// The outcome of a [CoriolisRoll].
+#[derive(Debug, PartialEq)]
+pub struct CoriolisResult {
+    /// How many dice showed a 6.
+    pub successes: u16,
+    /// Whether the roll was pushed.
+    pub pushed: bool,
+}
+impl CoriolisResult {
+    /// Evaluates the results of the single dice of an unpushed pool.
+    pub fn from_results(results: &[u16]) -> Self {
+        Self {
+            successes: results.iter().filter(|&&result| result == 6).count() as u16,
+            pushed: false,
+        }
+    }
+}
+
+impl Roll {
+    /// **Tries to parse input as a Coriolis dice pool (e.g. `5d6`).**
+    ///
+    /// * Whitespaces are ignored.
+    /// * Only d6s without a modifier are allowed.
+    /// * Enforces a limit of 100 dice per pool.
+    ///
+    /// # Examples
+    /// ```
+    /// use die_parser::Roll;
+    ///
+    /// let coriolis_roll = Roll::parse_roll_from_coriolis("5d6").unwrap();
+    /// assert_eq!(coriolis_roll.pool, Roll::new(6, 5, 0));
+    /// ```
+    pub fn parse_roll_from_coriolis(input: &str) -> Result<CoriolisRoll, RollError> {
+        let pool = super::parse_pool(input, 6)?;
+
+        Ok(CoriolisRoll { pool })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_roll_from_coriolis() {
+        let tests = [
+            ("5d6", Ok(Roll::new(6, 5, 0))),
+            ("5d6+1", Err(RollError::ParsingError)),
+            ("5d8", Err(RollError::DieTypeInvalid)),
+            ("0d6", Err(RollError::NoDiceToRoll)),
+        ];
+
+        for (input, expected_output) in tests {
+            let output = Roll::parse_roll_from_coriolis(input).map(|roll| roll.pool);
+            assert_eq!(output, expected_output);
+        }
+    }
+
+    #[test]
+    fn test_coriolis_result() {
+        let tests = [(vec![6, 6, 1], 2), (vec![5, 4], 0), (vec![], 0)];
+
+        for (results, expected_output) in tests {
+            assert_eq!(
+                CoriolisResult::from_results(&results),
+                CoriolisResult {
+                    successes: expected_output,
+                    pushed: false
+                }
+            );
+        }
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn test_roll_with_push() {
+        let mut rng = rand::thread_rng();
+        let coriolis_roll = Roll::parse_roll_from_coriolis("5d6").unwrap();
+
+        for _ in 0..100 {
+            let result = coriolis_roll.roll_with_push(&mut rng, true);
+            assert!(result.successes <= 5);
+            // Only a pool of nothing but sixes is left unpushed.
+            assert!(result.pushed || result.successes == 5);
+            assert!(!coriolis_roll.roll(&mut rng).pushed);
+        }
+    }
+}
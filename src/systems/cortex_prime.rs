@@ -0,0 +1,115 @@
+use crate::{Roll, RollError};
+
+/// The types of die used by Cortex Prime.
+const CORTEX_DIE_TYPES: [u16; 5] = [4, 6, 8, 10, 12];
+
+/// A Cortex Prime dice pool, made of single dice of different types.
+#[derive(Debug, PartialEq)]
+pub struct CortexPool {
+    /// The single dice in the pool.
+    pub dice: Vec<Roll>,
+}
+impl CortexPool {
+    /// Sums the two highest results, as done for Cortex Prime action resolution.
+    pub fn total_from_results(results: &[u16]) -> u16 {
+        let mut results = results.to_vec();
+        results.sort_unstable_by(|a, b| b.cmp(a));
+
+        results.iter().take(2).sum()
+    }
+}
+#[cfg(feature = "rand")]
+impl CortexPool {
+    /// Rolls every die of the pool and sums the two highest results.
+    pub fn roll<R: rand::Rng + ?Sized>(&self, rng: &mut R) -> u16 {
+        let results: Vec<u16> = self
+            .dice
+            .iter()
+            .map(|die| crate::rolling::roll_die(rng, die.number_of_sides))
+            .collect();
+
+        CortexPool::total_from_results(&results)
+    }
+}
+
+impl Roll {
+    /// **Tries to parse input as a Cortex Prime dice pool (e.g. `d6+d8+d4`).**
+    ///
+    /// * Whitespaces are ignored.
+    /// * Dice are separated by `+` and may be written with an amount (e.g. `2d8` adds two d8s).
+    /// * Valid die types are: d4, d6, d8, d10, d12
+    /// * Exploding dice, keeping or dropping dice and Fate dice return [`RollError::ParsingError`].
+    /// * Enforces a limit of 100 dice per pool.
+    ///
+    /// # Examples
+    /// ```
+    /// use die_parser::Roll;
+    ///
+    /// let cortex_pool = Roll::parse_roll_from_cortex_prime("d6+d8+d4").unwrap();
+    /// assert_eq!(cortex_pool.dice.len(), 3);
+    /// assert_eq!(cortex_pool.dice[1], Roll::new(8, 1, 0));
+    /// ```
+    pub fn parse_roll_from_cortex_prime(input: &str) -> Result<CortexPool, RollError> {
+        let whitespaceless = input.replace(' ', "");
+        let mut dice = Vec::new();
+
+        for term in whitespaceless.split('+') {
+            // A missing amount of dice means a single die.
+            let notation = match term.strip_prefix('d') {
+                Some(sides) => format!("1d{sides}"),
+                None => term.to_string(),
+            };
+
+            let roll = super::plain_roll(Roll::parse_modified_roll(&notation)?)?;
+            if roll.modifier != 0 {
+                return Err(RollError::ParsingError);
+            }
+            roll.check_roll_validity_with_die_types(100, &CORTEX_DIE_TYPES)?;
+
+            for _ in 0..roll.number_of_dice {
+                dice.push(Roll::new(roll.number_of_sides, 1, 0));
+            }
+        }
+
+        if dice.len() > 100 {
+            return Err(RollError::DiceExceedLimit);
+        }
+
+        Ok(CortexPool { dice })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_roll_from_cortex_prime() {
+        let tests = [
+            ("d6+d8+d4", Ok(vec![6, 8, 4])),
+            ("2d8 + d12", Ok(vec![8, 8, 12])),
+            ("d10", Ok(vec![10])),
+            ("d6+d20", Err(RollError::DieTypeInvalid)),
+            ("d6+", Err(RollError::ParsingError)),
+            ("d6-d8", Err(RollError::ParsingError)),
+            ("d6!+d8", Err(RollError::ParsingError)),
+            ("3d8kh1", Err(RollError::ParsingError)),
+            ("d6+dF", Err(RollError::ParsingError)),
+        ];
+
+        for (input, expected_output) in tests {
+            let output = Roll::parse_roll_from_cortex_prime(input)
+                .map(|pool| pool.dice.iter().map(|die| die.number_of_sides).collect());
+            assert_eq!(output, expected_output);
+        }
+    }
+
+    #[test]
+    fn test_total_from_results() {
+        let tests = [(vec![3, 7, 5, 1], 12), (vec![4], 4), (vec![], 0)];
+
+        for (results, expected_output) in tests {
+            assert_eq!(CortexPool::total_from_results(&results), expected_output);
+        }
+    }
+}
@@ -0,0 +1,80 @@
+use crate::{Roll, RollError};
+
+/// The highest difficulty tier in the Cypher System.
+const MAX_TIER: i32 = 7;
+
+impl Roll {
+    /// **Tries to parse input as a Cypher System roll (e.g. `1d20-3`).**
+    ///
+    /// * Whitespaces are ignored.
+    /// * The dice have to be exactly `1d20`.
+    ///
+    /// # Examples
+    /// ```
+    /// use die_parser::{Roll, RollError};
+    ///
+    /// let roll = Roll::parse_roll_from_cypher_notation("1d20-3");
+    /// assert_eq!(roll, Ok(Roll::new(20, 1, -3)));
+    ///
+    /// let invalid_roll = Roll::parse_roll_from_cypher_notation("2d20");
+    /// assert_eq!(invalid_roll, Err(RollError::ParsingError));
+    /// ```
+    pub fn parse_roll_from_cypher_notation(input: &str) -> Result<Roll, RollError> {
+        let result = super::parse_plain_roll(input)?;
+
+        if result.number_of_sides != 20 {
+            return Err(RollError::DieTypeInvalid);
+        } else if result.number_of_dice != 1 {
+            return Err(RollError::ParsingError);
+        }
+
+        Ok(result)
+    }
+
+    /// Determines the highest Cypher System difficulty tier a rolled result beats.
+    ///
+    /// Each tier takes 3 points, capped at tier 7.
+    ///
+    /// # Example
+    /// ```
+    /// use die_parser::Roll;
+    ///
+    /// assert_eq!(Roll::cypher_tier(10), 3);
+    /// assert_eq!(Roll::cypher_tier(25), 7);
+    /// ```
+    pub fn cypher_tier(result: i32) -> u8 {
+        (result / 3).clamp(0, MAX_TIER) as u8
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_roll_from_cypher_notation() {
+        let tests = [
+            ("1d20", Ok(Roll::new(20, 1, 0))),
+            ("1d20 - 3", Ok(Roll::new(20, 1, -3))),
+            ("2d20", Err(RollError::ParsingError)),
+            ("1d20!", Err(RollError::ParsingError)),
+            ("1d12", Err(RollError::DieTypeInvalid)),
+        ];
+
+        for (input, expected_output) in tests {
+            assert_eq!(
+                Roll::parse_roll_from_cypher_notation(input),
+                expected_output
+            );
+        }
+    }
+
+    #[test]
+    fn test_cypher_tier() {
+        let tests = [(-2, 0), (0, 0), (2, 0), (3, 1), (17, 5), (21, 7), (30, 7)];
+
+        for (result, expected_output) in tests {
+            assert_eq!(Roll::cypher_tier(result), expected_output);
+        }
+    }
+}
@@ -0,0 +1,135 @@
+use crate::{KeepMode, Roll, RollError};
+
+/// The types of die used by Dungeon Crawl Classics, including its unusual dice.
+const DCC_DIE_TYPES: [u16; 15] = [2, 3, 4, 5, 6, 7, 8, 10, 12, 14, 16, 20, 24, 30, 100];
+
+/// A Dungeon Crawl Classics funnel stat roll, which drops the lowest of `3d6`.
+#[derive(Debug, PartialEq)]
+pub struct DccFunnel {
+    /// The parsed roll, which is always `3d6dl1`.
+    pub stat: Roll,
+}
+#[cfg(feature = "rand")]
+impl DccFunnel {
+    /// Rolls a stat for a funnel character, ranging from 2 to 12.
+    pub fn roll_stat<R: rand::Rng + ?Sized>(&self, rng: &mut R) -> u8 {
+        let total = self.stat.roll_with_rng(rng);
+
+        u8::try_from(total).unwrap_or(u8::MAX)
+    }
+}
+
+impl Roll {
+    /// **Tries to parse input as Dungeon Crawl Classics roll notation (e.g. `1d7 + 2`).**
+    ///
+    /// * Whitespaces are ignored.
+    /// * Checks for validity of roll.[^1]
+    ///     * Enforces a limit of 100 dice per roll.
+    ///
+    /// # Examples
+    /// ```
+    /// use die_parser::{Roll, RollError};
+    ///
+    /// let roll = Roll::parse_roll_from_dcc("1d24");
+    /// assert_eq!(roll, Ok(Roll::new(24, 1, 0)));
+    ///
+    /// let invalid_roll = Roll::parse_roll_from_dcc("1d9");
+    /// assert_eq!(invalid_roll, Err(RollError::DieTypeInvalid));
+    /// ```
+    /// [^1]: Valid die types are: d2, d3, d4, d5, d6, d7, d8, d10, d12, d14, d16, d20, d24, d30, d100
+    pub fn parse_roll_from_dcc(input: &str) -> Result<Roll, RollError> {
+        let result = Roll::parse_modified_roll(input)?;
+
+        result.check_roll_validity_with_die_types(100, &DCC_DIE_TYPES)?;
+        Ok(result)
+    }
+
+    /// **Tries to parse input as a Dungeon Crawl Classics funnel stat roll (e.g. `3d6dl1`).**
+    ///
+    /// * Whitespaces are ignored.
+    /// * The spelled out form `roll 3d6 drop lowest` is accepted as well.
+    /// * The roll has to be exactly `3d6` dropping the lowest die.
+    ///
+    /// # Examples
+    /// ```
+    /// use die_parser::Roll;
+    ///
+    /// let funnel = Roll::parse_roll_from_dungeon_crawl_classics_funnel("roll 3d6 drop lowest").unwrap();
+    /// assert_eq!(funnel, Roll::parse_roll_from_dungeon_crawl_classics_funnel("3d6dl1").unwrap());
+    /// ```
+    pub fn parse_roll_from_dungeon_crawl_classics_funnel(
+        input: &str,
+    ) -> Result<DccFunnel, RollError> {
+        let input = input.trim();
+        let input = input.strip_prefix("roll").unwrap_or(input);
+        let notation = input.replace("drop lowest", "dl1");
+
+        let stat = Roll::parse_roll_from_dcc(&notation)?;
+        if stat.number_of_sides != 6 {
+            return Err(RollError::DieTypeInvalid);
+        } else if stat.number_of_dice != 3
+            || stat.modifier != 0
+            || stat.keep_mode != Some(KeepMode::DropLowest(1))
+        {
+            return Err(RollError::ParsingError);
+        }
+
+        Ok(DccFunnel { stat })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_roll_from_dcc() {
+        let tests = [
+            ("1d3", Ok(Roll::new(3, 1, 0))),
+            ("2d5+1", Ok(Roll::new(5, 2, 1))),
+            ("1d7", Ok(Roll::new(7, 1, 0))),
+            ("1d14", Ok(Roll::new(14, 1, 0))),
+            ("1d16", Ok(Roll::new(16, 1, 0))),
+            ("1d24 - 2", Ok(Roll::new(24, 1, -2))),
+            ("1d30", Ok(Roll::new(30, 1, 0))),
+            ("3d6", Ok(Roll::new(6, 3, 0))),
+            ("1d9", Err(RollError::DieTypeInvalid)),
+            ("4dF", Err(RollError::DieTypeInvalid)),
+            ("0d7", Err(RollError::NoDiceToRoll)),
+        ];
+
+        for (input, expected_output) in tests {
+            assert_eq!(Roll::parse_roll_from_dcc(input), expected_output);
+        }
+    }
+
+    #[test]
+    fn test_parse_roll_from_dungeon_crawl_classics_funnel() {
+        let stat = Roll::new(6, 3, 0).keep(KeepMode::DropLowest(1));
+        let tests = [
+            ("3d6dl1", Ok(stat)),
+            ("roll 3d6 drop lowest", Ok(stat)),
+            ("3d6", Err(RollError::ParsingError)),
+            ("4d6dl1", Err(RollError::ParsingError)),
+            ("3d6dl1+1", Err(RollError::ParsingError)),
+            ("3d8dl1", Err(RollError::DieTypeInvalid)),
+        ];
+
+        for (input, expected_output) in tests {
+            let output = Roll::parse_roll_from_dungeon_crawl_classics_funnel(input)
+                .map(|funnel| funnel.stat);
+            assert_eq!(output, expected_output);
+        }
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn test_roll_stat() {
+        let mut rng = rand::thread_rng();
+        let funnel = Roll::parse_roll_from_dungeon_crawl_classics_funnel("3d6dl1").unwrap();
+
+        for _ in 0..100 {
+            assert!((2..=12).contains(&funnel.roll_stat(&mut rng)));
+        }
+    }
+}
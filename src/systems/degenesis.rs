@@ -0,0 +1,103 @@
+use crate::{Roll, RollError};
+
+/// A Degenesis dice pool, in which every die at or below an attribute is a success.
+#[derive(Debug, PartialEq)]
+pub struct DegRoll {
+    /// The pool of d6s to be rolled.
+    pub pool: Roll,
+}
+#[cfg(feature = "rand")]
+impl DegRoll {
+    /// Rolls the pool and counts successes and triggers against an attribute.
+    pub fn roll<R: rand::Rng + ?Sized>(&self, rng: &mut R, attribute: u8) -> DegResult {
+        let results = crate::rolling::roll_dice(rng, self.pool.number_of_dice, 6);
+
+        DegResult::from_results(&results, attribute)
+    }
+}
+
+/// The outcome of a [DegRoll].
+#[derive(Debug, PartialEq)]
+pub struct DegResult {
+    /// How many dice showed the attribute or less.
+    pub successes: u16,
+    /// How many dice showed exactly the attribute.
+    pub triggers: u16,
+}
+impl DegResult {
+    /// Evaluates the results of the single dice of a pool against an attribute.
+    pub fn from_results(results: &[u16], attribute: u8) -> Self {
+        let attribute = u16::from(attribute);
+
+        Self {
+            successes: results
+                .iter()
+                .filter(|&&result| result <= attribute)
+                .count() as u16,
+            triggers: results
+                .iter()
+                .filter(|&&result| result == attribute)
+                .count() as u16,
+        }
+    }
+}
+
+impl Roll {
+    /// **Tries to parse input as a Degenesis dice pool (e.g. `6d6`).**
+    ///
+    /// * Whitespaces are ignored.
+    /// * Only d6s without a modifier are allowed.
+    /// * Enforces a limit of 100 dice per pool.
+    ///
+    /// # Examples
+    /// ```
+    /// use die_parser::Roll;
+    ///
+    /// let deg_roll = Roll::parse_roll_from_degenesis("6d6").unwrap();
+    /// assert_eq!(deg_roll.pool, Roll::new(6, 6, 0));
+    /// ```
+    pub fn parse_roll_from_degenesis(input: &str) -> Result<DegRoll, RollError> {
+        let pool = super::parse_pool(input, 6)?;
+
+        Ok(DegRoll { pool })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_roll_from_degenesis() {
+        let tests = [
+            ("6d6", Ok(Roll::new(6, 6, 0))),
+            ("6d6+1", Err(RollError::ParsingError)),
+            ("6d10", Err(RollError::DieTypeInvalid)),
+            ("0d6", Err(RollError::NoDiceToRoll)),
+        ];
+
+        for (input, expected_output) in tests {
+            let output = Roll::parse_roll_from_degenesis(input).map(|roll| roll.pool);
+            assert_eq!(output, expected_output);
+        }
+    }
+
+    #[test]
+    fn test_deg_result() {
+        let tests = [
+            ((vec![1, 4, 4, 6], 4), (3, 2)),
+            ((vec![5, 6], 4), (0, 0)),
+            ((vec![], 4), (0, 0)),
+        ];
+
+        for ((results, attribute), (successes, triggers)) in tests {
+            assert_eq!(
+                DegResult::from_results(&results, attribute),
+                DegResult {
+                    successes,
+                    triggers
+                }
+            );
+        }
+    }
+}
@@ -0,0 +1,99 @@
+use crate::{Roll, RollError};
+
+/// A Dragonbane dragon die, marked with a `dr` suffix.
+#[derive(Debug, PartialEq)]
+pub struct DragonbaneRoll {
+    /// The parsed roll, which is always `1d6`.
+    pub dragon_die: Roll,
+}
+#[cfg(feature = "rand")]
+impl DragonbaneRoll {
+    /// Rolls the dragon die and determines its outcome.
+    pub fn roll<R: rand::Rng + ?Sized>(&self, rng: &mut R) -> DragonbaneResult {
+        DragonbaneResult::from_result(crate::rolling::roll_die(rng, 6))
+    }
+}
+
+/// The outcome of a [DragonbaneRoll].
+#[derive(Debug, PartialEq)]
+pub enum DragonbaneResult {
+    /// The dragon die showed a 1, a great success.
+    Dragon,
+    /// The dragon die showed a 6, a failure with a complication.
+    Demon,
+    /// The dragon die showed 2 to 5.
+    Normal(u16),
+}
+impl DragonbaneResult {
+    /// Evaluates the result of the dragon die.
+    pub fn from_result(result: u16) -> Self {
+        match result {
+            1 => Self::Dragon,
+            6 => Self::Demon,
+            _ => Self::Normal(result),
+        }
+    }
+}
+
+impl Roll {
+    /// **Tries to parse input as a Dragonbane dragon die (e.g. `1d6dr`).**
+    ///
+    /// * Whitespaces are ignored.
+    /// * The roll has to be exactly `1d6`, followed by the `dr` suffix.
+    ///
+    /// # Examples
+    /// ```
+    /// use die_parser::Roll;
+    ///
+    /// let dragonbane_roll = Roll::parse_roll_from_dragonbane("1d6dr").unwrap();
+    /// assert_eq!(dragonbane_roll.dragon_die, Roll::new(6, 1, 0));
+    /// ```
+    pub fn parse_roll_from_dragonbane(input: &str) -> Result<DragonbaneRoll, RollError> {
+        let whitespaceless = input.replace(' ', "");
+
+        let notation = whitespaceless
+            .strip_suffix("dr")
+            .ok_or(RollError::ParsingError)?;
+
+        let dragon_die = super::parse_pool(notation, 6)?;
+        if dragon_die.number_of_dice != 1 {
+            return Err(RollError::ParsingError);
+        }
+
+        Ok(DragonbaneRoll { dragon_die })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_roll_from_dragonbane() {
+        let tests = [
+            ("1d6dr", Ok(Roll::new(6, 1, 0))),
+            ("1d6 dr", Ok(Roll::new(6, 1, 0))),
+            ("1d6", Err(RollError::ParsingError)),
+            ("2d6dr", Err(RollError::ParsingError)),
+            ("1d8dr", Err(RollError::DieTypeInvalid)),
+        ];
+
+        for (input, expected_output) in tests {
+            let output = Roll::parse_roll_from_dragonbane(input).map(|roll| roll.dragon_die);
+            assert_eq!(output, expected_output);
+        }
+    }
+
+    #[test]
+    fn test_dragonbane_result() {
+        let tests = [
+            (1, DragonbaneResult::Dragon),
+            (3, DragonbaneResult::Normal(3)),
+            (6, DragonbaneResult::Demon),
+        ];
+
+        for (result, expected_output) in tests {
+            assert_eq!(DragonbaneResult::from_result(result), expected_output);
+        }
+    }
+}
@@ -0,0 +1,128 @@
+use crate::{Roll, RollError};
+
+/// The six Dungeon World stats, by their three-letter codes.
+const STATS: [&str; 6] = ["STR", "DEX", "CON", "INT", "WIS", "CHA"];
+
+/// A Dungeon World move, which always rolls 2d6 plus a stat.
+#[derive(Debug, PartialEq)]
+pub struct DwRoll {
+    /// The parsed roll, with a numeric modifier if one was given.
+    pub roll: Roll,
+    /// The stat added to the roll (e.g. `CON`), if it was given by name.
+    pub stat: Option<String>,
+}
+impl DwRoll {
+    /// Determines the outcome band of a rolled result.
+    ///
+    /// | Result | Outcome                          |
+    /// |--------|----------------------------------|
+    /// | 6-     | [`DwOutcome::Miss`]              |
+    /// | 7-9    | [`DwOutcome::PartialSuccess`]    |
+    /// | 10+    | [`DwOutcome::FullSuccess`]       |
+    pub fn outcome(result: i32) -> DwOutcome {
+        match result {
+            i32::MIN..=6 => DwOutcome::Miss,
+            7..=9 => DwOutcome::PartialSuccess,
+            _ => DwOutcome::FullSuccess,
+        }
+    }
+}
+
+/// The outcome band of a [DwRoll].
+#[derive(Debug, PartialEq)]
+pub enum DwOutcome {
+    /// A result of 6 or less.
+    Miss,
+    /// A result of 7 to 9, a success with a cost.
+    PartialSuccess,
+    /// A result of 10 or more.
+    FullSuccess,
+}
+
+impl Roll {
+    /// **Tries to parse input as a Dungeon World move (e.g. `2d6+CON`).**
+    ///
+    /// * Whitespaces are ignored.
+    /// * The dice have to be exactly `2d6`.
+    /// * The stat can be given as a number or as one of `STR`, `DEX`, `CON`, `INT`, `WIS` or `CHA`.
+    ///     * Stat codes are case-insensitive.
+    ///
+    /// # Examples
+    /// ```
+    /// use die_parser::Roll;
+    /// use die_parser::systems::{DwOutcome, DwRoll};
+    ///
+    /// let dw_roll = Roll::parse_roll_from_dungeon_world("2d6+CON").unwrap();
+    /// assert_eq!(dw_roll.roll, Roll::new(6, 2, 0));
+    /// assert_eq!(dw_roll.stat, Some(String::from("CON")));
+    /// assert_eq!(DwRoll::outcome(10), DwOutcome::FullSuccess);
+    /// ```
+    pub fn parse_roll_from_dungeon_world(input: &str) -> Result<DwRoll, RollError> {
+        let whitespaceless = input.replace(' ', "");
+
+        let (notation, stat) = match whitespaceless.rsplit_once('+') {
+            Some((notation, stat)) if stat.starts_with(|c: char| c.is_ascii_alphabetic()) => {
+                let stat = stat.to_ascii_uppercase();
+                if !STATS.contains(&stat.as_str()) {
+                    return Err(RollError::ParsingError);
+                }
+                (notation, Some(stat))
+            }
+            _ => (whitespaceless.as_str(), None),
+        };
+
+        let roll = super::parse_plain_roll(notation)?;
+
+        if roll.number_of_sides != 6 {
+            return Err(RollError::DieTypeInvalid);
+        } else if roll.number_of_dice != 2 || (stat.is_some() && roll.modifier != 0) {
+            return Err(RollError::ParsingError);
+        }
+
+        Ok(DwRoll { roll, stat })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_roll_from_dungeon_world() {
+        let tests = [
+            ("2d6+CON", Ok((Roll::new(6, 2, 0), Some("CON")))),
+            ("2d6 + wis", Ok((Roll::new(6, 2, 0), Some("WIS")))),
+            ("2d6+2", Ok((Roll::new(6, 2, 2), None))),
+            ("2d6-1", Ok((Roll::new(6, 2, -1), None))),
+            ("2d6+LCK", Err(RollError::ParsingError)),
+            ("2d6+1+CON", Err(RollError::ParsingError)),
+            ("3d6+CON", Err(RollError::ParsingError)),
+            ("2d6!+CON", Err(RollError::ParsingError)),
+            ("2d8+CON", Err(RollError::DieTypeInvalid)),
+        ];
+
+        for (input, expected_output) in tests {
+            let output = Roll::parse_roll_from_dungeon_world(input);
+            let expected_output = expected_output.map(|(roll, stat)| DwRoll {
+                roll,
+                stat: stat.map(String::from),
+            });
+            assert_eq!(output, expected_output);
+        }
+    }
+
+    #[test]
+    fn test_outcome() {
+        let tests = [
+            (2, DwOutcome::Miss),
+            (6, DwOutcome::Miss),
+            (7, DwOutcome::PartialSuccess),
+            (9, DwOutcome::PartialSuccess),
+            (10, DwOutcome::FullSuccess),
+        ];
+
+        for (result, expected_output) in tests {
+            assert_eq!(DwRoll::outcome(result), expected_output);
+        }
+    }
+}
@@ -0,0 +1,127 @@
+use crate::{DieKind, Roll, RollError, DIE_TYPES};
+
+/// The Fate ladder from -4 to +8.
+const LADDER: [&str; 13] = [
+    "Horrifying",
+    "Catastrophic",
+    "Terrible",
+    "Poor",
+    "Mediocre",
+    "Average",
+    "Fair",
+    "Good",
+    "Great",
+    "Superb",
+    "Fantastic",
+    "Epic",
+    "Legendary",
+];
+
+/// A Fate Core roll of four Fate dice, each showing -1, 0 or +1.
+#[derive(Debug, PartialEq)]
+pub struct FateRoll {
+    /// The four Fate dice and the value added to their result.
+    pub roll: Roll,
+}
+impl FateRoll {
+    /// Returns the adjective of the Fate ladder for a result (e.g. `+3` is `"Good"`).
+    ///
+    /// Results beyond the ladder are clamped to its ends.
+    pub fn ladder_rung(result: i32) -> &'static str {
+        LADDER[(result.clamp(-4, 8) + 4) as usize]
+    }
+}
+#[cfg(feature = "rand")]
+impl FateRoll {
+    /// Rolls the four Fate dice and adds the modifier.
+    pub fn roll<R: rand::Rng + ?Sized>(&self, rng: &mut R) -> i32 {
+        self.roll.roll_with_rng(rng)
+    }
+}
+
+impl Roll {
+    /// **Tries to parse input as a Fate Core roll (e.g. `4dF` or `4dF+2`).**
+    ///
+    /// * Whitespaces are ignored.
+    /// * The dice have to be exactly `4dF`, the `F` may be lowercase.
+    /// * Nothing may follow the modifier.
+    ///
+    /// # Examples
+    /// ```
+    /// use die_parser::Roll;
+    /// use die_parser::systems::FateRoll;
+    ///
+    /// let fate_roll = Roll::parse_roll_from_fate_dice("4dF+2").unwrap();
+    /// assert_eq!(fate_roll.roll, Roll::new_fate(4, 2));
+    /// assert_eq!(FateRoll::ladder_rung(3), "Good");
+    /// ```
+    pub fn parse_roll_from_fate_dice(input: &str) -> Result<FateRoll, RollError> {
+        // Remove whitespaces.
+        let whitespaceless = input.replace(' ', "");
+
+        let (remainder, roll) = Roll::parse_roll_notation(&whitespaceless)?;
+        if !remainder.is_empty()
+            || roll.die_kind != DieKind::Fate
+            || roll.number_of_dice != 4
+            || roll.keep_mode.is_some()
+        {
+            return Err(RollError::ParsingError);
+        }
+        roll.check_parsed_roll_validity(4, &DIE_TYPES)?;
+
+        Ok(FateRoll { roll })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_roll_from_fate_dice() {
+        let tests = [
+            ("4dF", Ok(0)),
+            ("4df + 2", Ok(2)),
+            ("4dF-1", Ok(-1)),
+            ("3dF", Err(RollError::ParsingError)),
+            ("4d6", Err(RollError::ParsingError)),
+            ("4dF+2abc", Err(RollError::ParsingError)),
+            ("4dFkh3", Err(RollError::ParsingError)),
+            ("4dF!", Err(RollError::ConflictingOptions)),
+        ];
+
+        for (input, expected_output) in tests {
+            let output = Roll::parse_roll_from_fate_dice(input).map(|roll| roll.roll.modifier);
+            assert_eq!(output, expected_output);
+        }
+    }
+
+    #[test]
+    fn test_ladder_rung() {
+        let tests = [
+            (-5, "Horrifying"),
+            (-2, "Terrible"),
+            (0, "Mediocre"),
+            (3, "Good"),
+            (8, "Legendary"),
+            (12, "Legendary"),
+        ];
+
+        for (result, expected_output) in tests {
+            assert_eq!(FateRoll::ladder_rung(result), expected_output);
+        }
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn test_roll() {
+        let mut rng = rand::thread_rng();
+        let fate_roll = FateRoll {
+            roll: Roll::new_fate(4, 0),
+        };
+
+        for _ in 0..100 {
+            assert!((-4..=4).contains(&fate_roll.roll(&mut rng)));
+        }
+    }
+}
@@ -0,0 +1,225 @@
+use nom::character::complete::{anychar, char};
+use nom::multi::separated_list1;
+use nom::sequence::{separated_pair, tuple};
+use nom::IResult;
+
+use crate::{parse_numbers, Roll, RollError};
+
+/// A Forbidden Lands roll, made of a pool of attribute dice and a pool of gear dice.
+#[derive(Debug, PartialEq)]
+pub struct ForbiddenLandsRoll {
+    /// The attribute dice, marked with `a`.
+    pub attribute: Roll,
+    /// The gear dice, marked with `g`.
+    pub gear: Roll,
+}
+#[cfg(feature = "rand")]
+impl ForbiddenLandsRoll {
+    /// Rolls both pools and counts successes and stress.
+    pub fn roll<R: rand::Rng + ?Sized>(&self, rng: &mut R) -> ForbiddenLandsResult {
+        let attribute = crate::rolling::roll_dice(rng, self.attribute.number_of_dice, 6);
+        let gear = crate::rolling::roll_dice(rng, self.gear.number_of_dice, 6);
+
+        ForbiddenLandsResult::from_results(&attribute, &gear)
+    }
+}
+
+/// The outcome of a [ForbiddenLandsRoll].
+#[derive(Debug, PartialEq)]
+pub struct ForbiddenLandsResult {
+    /// How many dice of either pool showed a 6.
+    pub successes: u16,
+    /// How many attribute dice showed a 1.
+    pub stress: u16,
+}
+impl ForbiddenLandsResult {
+    /// Evaluates the results of the single dice of both pools.
+    pub fn from_results(attribute: &[u16], gear: &[u16]) -> Self {
+        let successes = attribute.iter().chain(gear).filter(|&&result| result == 6);
+
+        Self {
+            successes: successes.count() as u16,
+            stress: attribute.iter().filter(|&&result| result == 1).count() as u16,
+        }
+    }
+}
+
+/// A Forbidden Lands roll that includes artifact dice, counted per type of die.
+#[derive(Debug, PartialEq)]
+pub struct ForbiddenLandsPool {
+    /// The amount of base d6s, marked with `b`.
+    pub d6: u16,
+    /// The amount of artifact d8s, marked with `q`.
+    pub d8: u16,
+    /// The amount of artifact d10s, marked with `q`.
+    pub d10: u16,
+    /// The amount of artifact d12s, marked with `q`.
+    pub d12: u16,
+}
+
+impl Roll {
+    /// **Tries to parse input as Forbidden Lands dice pools (e.g. `3d6a+2d6g`).**
+    ///
+    /// * Whitespaces are ignored.
+    /// * Attribute dice are marked with `a` and gear dice with `g`.
+    ///     * Either pool may be left out, but neither may be given twice.
+    /// * Only d6s are allowed.
+    /// * Enforces a limit of 100 dice per pool.
+    ///
+    /// # Examples
+    /// ```
+    /// use die_parser::Roll;
+    ///
+    /// let forbidden_lands_roll = Roll::parse_roll_from_forbidden_lands("3d6a+2d6g").unwrap();
+    /// assert_eq!(forbidden_lands_roll.attribute, Roll::new(6, 3, 0));
+    /// assert_eq!(forbidden_lands_roll.gear, Roll::new(6, 2, 0));
+    /// ```
+    pub fn parse_roll_from_forbidden_lands(input: &str) -> Result<ForbiddenLandsRoll, RollError> {
+        let mut attribute = None;
+        let mut gear = None;
+
+        for (pool_type, pool) in parse_marked_pools(input)? {
+            if pool.number_of_sides != 6 {
+                return Err(RollError::DieTypeInvalid);
+            }
+
+            let slot = match pool_type {
+                'a' => &mut attribute,
+                'g' => &mut gear,
+                _ => return Err(RollError::ParsingError),
+            };
+            if slot.replace(pool).is_some() {
+                return Err(RollError::ParsingError);
+            }
+        }
+
+        Ok(ForbiddenLandsRoll {
+            attribute: attribute.unwrap_or(Roll::new(6, 0, 0)),
+            gear: gear.unwrap_or(Roll::new(6, 0, 0)),
+        })
+    }
+
+    /// **Tries to parse input as Forbidden Lands dice pools with artifact dice (e.g. `2d8q+1d6b`).**
+    ///
+    /// * Whitespaces are ignored.
+    /// * Base dice are d6s marked with `b`.
+    /// * Artifact dice are d8s, d10s or d12s marked with `q`.
+    ///     * Pools of the same type of die are added up.
+    /// * Enforces a limit of 100 dice per pool.
+    ///
+    /// # Examples
+    /// ```
+    /// use die_parser::Roll;
+    ///
+    /// let pool = Roll::parse_roll_from_forbidden_lands_artifact("2d8q+1d6b").unwrap();
+    /// assert_eq!((pool.d6, pool.d8), (1, 2));
+    /// ```
+    pub fn parse_roll_from_forbidden_lands_artifact(
+        input: &str,
+    ) -> Result<ForbiddenLandsPool, RollError> {
+        let mut pool = ForbiddenLandsPool {
+            d6: 0,
+            d8: 0,
+            d10: 0,
+            d12: 0,
+        };
+
+        for (pool_type, dice) in parse_marked_pools(input)? {
+            let count = match (pool_type, dice.number_of_sides) {
+                ('b', 6) => &mut pool.d6,
+                ('q', 8) => &mut pool.d8,
+                ('q', 10) => &mut pool.d10,
+                ('q', 12) => &mut pool.d12,
+                ('b' | 'q', _) => return Err(RollError::DieTypeInvalid),
+                _ => return Err(RollError::ParsingError),
+            };
+            *count = count.saturating_add(dice.number_of_dice);
+        }
+
+        Ok(pool)
+    }
+}
+
+/// Tries to parse `+`-separated pools of dice, each marked by a letter (e.g. `3d6a+2d6g`).
+///
+/// Every pool is checked for validity of roll.
+pub(super) fn parse_marked_pools(input: &str) -> Result<Vec<(char, Roll)>, RollError> {
+    // Remove whitespaces.
+    let whitespaceless = input.replace(' ', "");
+
+    let pools = match separated_list1(char('+'), parse_marked_pool)(&whitespaceless) {
+        Ok(("", pools)) => pools,
+        _ => return Err(RollError::ParsingError),
+    };
+
+    pools
+        .into_iter()
+        .map(|((number_of_dice, number_of_sides), pool_type)| {
+            let pool = Roll::new(number_of_sides, number_of_dice, 0);
+            pool.check_roll_validity(100)?;
+            Ok((pool_type, pool))
+        })
+        .collect()
+}
+
+/// Tries to parse a single pool of dice marked by a letter (e.g. `3d6a`).
+fn parse_marked_pool(s: &str) -> IResult<&str, ((u16, u16), char)> {
+    tuple((
+        separated_pair(parse_numbers, char('d'), parse_numbers),
+        anychar,
+    ))(s)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_roll_from_forbidden_lands() {
+        let tests = [
+            ("3d6a+2d6g", Ok((3, 2))),
+            ("2d6g + 4d6a", Ok((4, 2))),
+            ("5d6a", Ok((5, 0))),
+            ("3d6a+1d6a", Err(RollError::ParsingError)),
+            ("3d6a+2d6x", Err(RollError::ParsingError)),
+            ("3d6", Err(RollError::ParsingError)),
+            ("3d8a", Err(RollError::DieTypeInvalid)),
+        ];
+
+        for (input, expected_output) in tests {
+            let output = Roll::parse_roll_from_forbidden_lands(input)
+                .map(|roll| (roll.attribute.number_of_dice, roll.gear.number_of_dice));
+            assert_eq!(output, expected_output);
+        }
+    }
+
+    #[test]
+    fn test_parse_roll_from_forbidden_lands_artifact() {
+        let tests = [
+            ("2d8q+1d6b", Ok((1, 2, 0, 0))),
+            ("1d12q + 1d10q + 3d6b", Ok((3, 0, 1, 1))),
+            ("1d8q+1d8q", Ok((0, 2, 0, 0))),
+            ("2d6q", Err(RollError::DieTypeInvalid)),
+            ("2d8b", Err(RollError::DieTypeInvalid)),
+            ("2d8a", Err(RollError::ParsingError)),
+        ];
+
+        for (input, expected_output) in tests {
+            let output = Roll::parse_roll_from_forbidden_lands_artifact(input)
+                .map(|pool| (pool.d6, pool.d8, pool.d10, pool.d12));
+            assert_eq!(output, expected_output);
+        }
+    }
+
+    #[test]
+    fn test_forbidden_lands_result() {
+        let result = ForbiddenLandsResult::from_results(&[6, 1, 1, 3], &[6, 6, 1]);
+        assert_eq!(
+            result,
+            ForbiddenLandsResult {
+                successes: 3,
+                stress: 2
+            }
+        );
+    }
+}
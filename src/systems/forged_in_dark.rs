@@ -0,0 +1,101 @@
+use crate::{Roll, RollError};
+
+/// A Forged in the Dark dice pool, of which only the highest die counts.
+#[derive(Debug, PartialEq)]
+pub struct FitdRoll {
+    /// The number of d6s to be rolled.
+    ///
+    /// A negative pool rolls its absolute value in dice and takes the lowest one instead.
+    pub pool: i8,
+}
+impl FitdRoll {
+    /// Picks the die that counts from the results of the single dice of the pool.
+    pub fn result_from(&self, results: &[u16]) -> Option<u16> {
+        match self.pool.is_negative() {
+            true => results.iter().min().copied(),
+            false => results.iter().max().copied(),
+        }
+    }
+}
+#[cfg(feature = "rand")]
+impl FitdRoll {
+    /// Rolls the pool and returns the die that counts.
+    pub fn roll<R: rand::Rng + ?Sized>(&self, rng: &mut R) -> u16 {
+        let results = crate::rolling::roll_dice(rng, self.pool.unsigned_abs().into(), 6);
+
+        self.result_from(&results).unwrap_or(1)
+    }
+}
+
+impl Roll {
+    /// **Tries to parse input as a Forged in the Dark dice pool (e.g. `3d` or `0d`).**
+    ///
+    /// * Whitespaces are ignored.
+    /// * Only d6s without a modifier are allowed, so the type of die may be omitted.
+    /// * A pool of zero dice rolls 2d6 and takes the lowest, which is stored as a pool of `-2`.
+    /// * Enforces a limit of 100 dice per pool.
+    ///
+    /// # Examples
+    /// ```
+    /// use die_parser::Roll;
+    ///
+    /// let fitd_roll = Roll::parse_roll_from_forged_in_dark("0d").unwrap();
+    /// assert_eq!(fitd_roll.pool, -2);
+    /// ```
+    pub fn parse_roll_from_forged_in_dark(input: &str) -> Result<FitdRoll, RollError> {
+        let whitespaceless = input.replace(' ', "");
+        if whitespaceless == "0d" || whitespaceless == "0d6" {
+            return Ok(FitdRoll { pool: -2 });
+        }
+
+        // Forged in the Dark shares the implicit d6 with GURPS.
+        let pool = Roll::parse_roll_from_gurps_notation(&whitespaceless)?;
+
+        if pool.number_of_sides != 6 {
+            return Err(RollError::DieTypeInvalid);
+        } else if pool.modifier != 0 {
+            return Err(RollError::ParsingError);
+        }
+
+        Ok(FitdRoll {
+            pool: pool.number_of_dice as i8,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_roll_from_forged_in_dark() {
+        let tests = [
+            ("3d", Ok(3)),
+            ("3d6", Ok(3)),
+            ("0d", Ok(-2)),
+            ("0d6", Ok(-2)),
+            ("0d+1", Err(RollError::NoDiceToRoll)),
+            ("3d+1", Err(RollError::ParsingError)),
+            ("3d8", Err(RollError::DieTypeInvalid)),
+            ("101d", Err(RollError::DiceExceedLimit)),
+        ];
+
+        for (input, expected_output) in tests {
+            let output = Roll::parse_roll_from_forged_in_dark(input).map(|roll| roll.pool);
+            assert_eq!(output, expected_output);
+        }
+    }
+
+    #[test]
+    fn test_result_from() {
+        let tests = [
+            (3, vec![2, 5, 4], Some(5)),
+            (-2, vec![2, 5], Some(2)),
+            (1, vec![], None),
+        ];
+
+        for (pool, results, expected_output) in tests {
+            assert_eq!(FitdRoll { pool }.result_from(&results), expected_output);
+        }
+    }
+}
@@ -0,0 +1,82 @@
+use crate::{Roll, RollError};
+
+impl Roll {
+    /// **Tries to parse input as roll notation (e.g. `2d6 + 3`) and spends fortune points on it.**
+    ///
+    /// * Parses and validates the input like [`Roll::parse_roll()`] does.
+    /// * Adds `fp` to the modifier afterwards.
+    ///
+    /// # Examples
+    /// ```
+    /// use die_parser::Roll;
+    ///
+    /// let fortune_roll = Roll::parse_roll_with_fortune_point("2d6+3", 2);
+    /// assert_eq!(fortune_roll, Ok(Roll::new(6, 2, 5)));
+    /// ```
+    pub fn parse_roll_with_fortune_point(input: &str, fp: i32) -> Result<Roll, RollError> {
+        Roll::parse_roll(input)?.add_modifier(fp)
+    }
+
+    /// **Notates a roll that `fp` fortune points have been spent on, annotating them separately.**
+    ///
+    /// * The notation shows the modifier the roll had before the fortune points were added.
+    ///
+    /// # Examples
+    /// ```
+    /// use die_parser::Roll;
+    ///
+    /// let fortune_roll = Roll::parse_roll_with_fortune_point("2d6+3", 2).unwrap();
+    /// assert_eq!(fortune_roll.to_fortune_point_notation(2), "2d6+3 (FP:+2)");
+    /// ```
+    pub fn to_fortune_point_notation(&self, fp: i32) -> String {
+        let original_roll = Roll {
+            modifier: self.modifier.saturating_sub(fp),
+            ..*self
+        };
+
+        format!("{} (FP:{fp:+})", original_roll.to_notation())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::KeepMode;
+
+    #[test]
+    fn test_parse_roll_with_fortune_point() {
+        let tests = [
+            ("2d6+3", 2, Roll::new(6, 2, 5), "2d6+3 (FP:+2)"),
+            ("1d20", -1, Roll::new(20, 1, -1), "1d20 (FP:-1)"),
+            ("1d20-4", 4, Roll::new(20, 1, 0), "1d20-4 (FP:+4)"),
+            (
+                "4d6kh3+1",
+                1,
+                Roll::new(6, 4, 2).keep(KeepMode::Highest(3)),
+                "4d6kh3+1 (FP:+1)",
+            ),
+            ("2d6!", 2, Roll::new(6, 2, 2).exploding(), "2d6! (FP:+2)"),
+            ("4dF", -1, Roll::new_fate(4, -1), "4dF (FP:-1)"),
+        ];
+
+        for (input, fp, expected_roll, expected_display) in tests {
+            let output = Roll::parse_roll_with_fortune_point(input, fp).unwrap();
+            assert_eq!(output, expected_roll);
+            assert_eq!(output.to_fortune_point_notation(fp), expected_display);
+        }
+    }
+
+    #[test]
+    fn test_err_parse_roll_with_fortune_point() {
+        let tests = [
+            ("1d20+1", i32::MAX, RollError::ModifierOverflow),
+            ("1d20-1", i32::MIN, RollError::ModifierOverflow),
+            ("1d7", 1, RollError::DieTypeInvalid),
+        ];
+
+        for (input, fp, expected_output) in tests {
+            let output = Roll::parse_roll_with_fortune_point(input, fp).unwrap_err();
+            assert_eq!(output, expected_output);
+        }
+    }
+}
@@ -0,0 +1,272 @@
+use crate::{parse_numbers, Roll, RollError};
+
+/// The symbols on a single face of a Genesys die.
+///
+/// Positive dice show `(successes, advantages, triumphs)`, negative dice show `(failures, threats, despairs)`.
+#[cfg(feature = "rand")]
+type Face = (u16, u16, u16);
+
+/// The faces of a boost die (d6).
+#[cfg(feature = "rand")]
+const BOOST_FACES: [Face; 6] = [
+    (0, 0, 0),
+    (0, 0, 0),
+    (1, 0, 0),
+    (1, 1, 0),
+    (0, 2, 0),
+    (0, 1, 0),
+];
+/// The faces of a setback die (d6).
+#[cfg(feature = "rand")]
+const SETBACK_FACES: [Face; 6] = [
+    (0, 0, 0),
+    (0, 0, 0),
+    (1, 0, 0),
+    (1, 0, 0),
+    (0, 1, 0),
+    (0, 1, 0),
+];
+/// The faces of an ability die (d8).
+#[cfg(feature = "rand")]
+const ABILITY_FACES: [Face; 8] = [
+    (0, 0, 0),
+    (1, 0, 0),
+    (1, 0, 0),
+    (2, 0, 0),
+    (0, 1, 0),
+    (0, 1, 0),
+    (1, 1, 0),
+    (0, 2, 0),
+];
+/// The faces of a difficulty die (d8).
+#[cfg(feature = "rand")]
+const DIFFICULTY_FACES: [Face; 8] = [
+    (0, 0, 0),
+    (1, 0, 0),
+    (2, 0, 0),
+    (0, 1, 0),
+    (0, 1, 0),
+    (0, 1, 0),
+    (0, 2, 0),
+    (1, 1, 0),
+];
+/// The faces of a proficiency die (d12). A triumph also counts as a success.
+#[cfg(feature = "rand")]
+const PROFICIENCY_FACES: [Face; 12] = [
+    (0, 0, 0),
+    (1, 0, 0),
+    (1, 0, 0),
+    (2, 0, 0),
+    (2, 0, 0),
+    (0, 1, 0),
+    (1, 1, 0),
+    (1, 1, 0),
+    (1, 1, 0),
+    (0, 2, 0),
+    (0, 2, 0),
+    (1, 0, 1),
+];
+/// The faces of a challenge die (d12). A despair also counts as a failure.
+#[cfg(feature = "rand")]
+const CHALLENGE_FACES: [Face; 12] = [
+    (0, 0, 0),
+    (1, 0, 0),
+    (1, 0, 0),
+    (2, 0, 0),
+    (2, 0, 0),
+    (0, 1, 0),
+    (0, 1, 0),
+    (1, 1, 0),
+    (1, 1, 0),
+    (0, 2, 0),
+    (0, 2, 0),
+    (1, 0, 1),
+];
+
+/// How many dice of each type a Genesys roll uses.
+#[derive(Debug, Default, PartialEq)]
+pub struct GenesysDice {
+    /// Green d8s, marked with `Ab`.
+    pub ability: u16,
+    /// Yellow d12s, marked with `Pr`.
+    pub proficiency: u16,
+    /// Blue d6s, marked with `Bo`.
+    pub boost: u16,
+    /// Purple d8s, marked with `Di`.
+    pub difficulty: u16,
+    /// Red d12s, marked with `Ch`.
+    pub challenge: u16,
+    /// Black d6s, marked with `Se`.
+    pub setback: u16,
+}
+#[cfg(feature = "rand")]
+impl GenesysDice {
+    /// Rolls every die and counts the symbols shown.
+    pub fn roll<R: rand::Rng + ?Sized>(&self, rng: &mut R) -> GenesysResult {
+        let mut result = GenesysResult::default();
+
+        let mut roll_faces = |amount: u16, faces: &[Face]| {
+            (0..amount)
+                .map(|_| faces[rng.gen_range(0..faces.len())])
+                .fold((0, 0, 0), |sum, face| {
+                    (sum.0 + face.0, sum.1 + face.1, sum.2 + face.2)
+                })
+        };
+
+        for (amount, faces) in [
+            (self.ability, &ABILITY_FACES[..]),
+            (self.proficiency, &PROFICIENCY_FACES[..]),
+            (self.boost, &BOOST_FACES[..]),
+        ] {
+            let (successes, advantages, triumphs) = roll_faces(amount, faces);
+            result.successes += successes;
+            result.advantages += advantages;
+            result.triumphs += triumphs;
+        }
+
+        for (amount, faces) in [
+            (self.difficulty, &DIFFICULTY_FACES[..]),
+            (self.challenge, &CHALLENGE_FACES[..]),
+            (self.setback, &SETBACK_FACES[..]),
+        ] {
+            let (failures, threats, despairs) = roll_faces(amount, faces);
+            result.failures += failures;
+            result.threats += threats;
+            result.despairs += despairs;
+        }
+
+        result
+    }
+}
+
+/// The symbols shown after rolling [GenesysDice].
+#[derive(Debug, Default, PartialEq)]
+pub struct GenesysResult {
+    /// Successes, including those of triumphs.
+    pub successes: u16,
+    /// Failures, including those of despairs.
+    pub failures: u16,
+    /// Advantages.
+    pub advantages: u16,
+    /// Threats.
+    pub threats: u16,
+    /// Triumphs.
+    pub triumphs: u16,
+    /// Despairs.
+    pub despairs: u16,
+}
+
+impl Roll {
+    /// **Tries to parse input as Genesys dice (e.g. `2Ab+1Pr+2Di`).**
+    ///
+    /// * Whitespaces are ignored.
+    /// * Each term is an amount followed by a two letter code:
+    ///     * `Ab`: Ability, `Pr`: Proficiency, `Bo`: Boost
+    ///     * `Di`: Difficulty, `Ch`: Challenge, `Se`: Setback
+    /// * The letters of a code may be given in any case and order (e.g. `bA` for ability).
+    /// * Enforces a limit of 100 dice per roll.
+    ///
+    /// # Examples
+    /// ```
+    /// use die_parser::Roll;
+    ///
+    /// let genesys_dice = Roll::parse_roll_from_genesys("2bA+1pR").unwrap();
+    /// assert_eq!(genesys_dice.ability, 2);
+    /// assert_eq!(genesys_dice.proficiency, 1);
+    /// ```
+    pub fn parse_roll_from_genesys(input: &str) -> Result<GenesysDice, RollError> {
+        let whitespaceless = input.replace(' ', "");
+        let mut dice = GenesysDice::default();
+
+        for term in whitespaceless.split('+') {
+            let (code, amount) = match parse_numbers(term) {
+                Ok(v) => v,
+                Err(_) => return Err(RollError::ParsingError),
+            };
+
+            // Sort the letters, so they may be given in any order.
+            let mut code: Vec<char> = code.to_lowercase().chars().collect();
+            code.sort_unstable();
+
+            let count = match code.iter().collect::<String>().as_str() {
+                "ab" => &mut dice.ability,
+                "pr" => &mut dice.proficiency,
+                "bo" => &mut dice.boost,
+                "di" => &mut dice.difficulty,
+                "ch" => &mut dice.challenge,
+                "es" => &mut dice.setback,
+                _ => return Err(RollError::ParsingError),
+            };
+            *count = count.saturating_add(amount);
+        }
+
+        let total = [
+            dice.ability,
+            dice.proficiency,
+            dice.boost,
+            dice.difficulty,
+            dice.challenge,
+            dice.setback,
+        ]
+        .iter()
+        .map(|&amount| amount as u32)
+        .sum::<u32>();
+
+        match total {
+            0 => Err(RollError::NoDiceToRoll),
+            1..=100 => Ok(dice),
+            _ => Err(RollError::DiceExceedLimit),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_roll_from_genesys() {
+        let tests = [
+            (
+                "2bA+1pR",
+                Ok(GenesysDice {
+                    ability: 2,
+                    proficiency: 1,
+                    ..Default::default()
+                }),
+            ),
+            (
+                "2Ab + 1Pr + 2Di + 1Se + 1Ab",
+                Ok(GenesysDice {
+                    ability: 3,
+                    proficiency: 1,
+                    difficulty: 2,
+                    setback: 1,
+                    ..Default::default()
+                }),
+            ),
+            ("0Ab", Err(RollError::NoDiceToRoll)),
+            ("60Ab+41Ch", Err(RollError::DiceExceedLimit)),
+            ("2Xy", Err(RollError::ParsingError)),
+            ("Ab", Err(RollError::ParsingError)),
+        ];
+
+        for (input, expected_output) in tests {
+            assert_eq!(Roll::parse_roll_from_genesys(input), expected_output);
+        }
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn test_genesys_roll() {
+        let mut rng = rand::thread_rng();
+        let genesys_dice = Roll::parse_roll_from_genesys("3Pr+2Se").unwrap();
+
+        for _ in 0..100 {
+            let result = genesys_dice.roll(&mut rng);
+            assert!(result.successes <= 6 && result.triumphs <= 3);
+            assert!(result.failures + result.threats <= 2);
+            assert_eq!(result.despairs, 0);
+        }
+    }
+}
@@ -0,0 +1,87 @@
+use nom::character::complete::one_of;
+use nom::combinator::opt;
+use nom::sequence::separated_pair;
+use nom::IResult;
+
+use crate::{parse_modifier, parse_numbers, Roll, RollError};
+
+impl Roll {
+    /// **Tries to parse input as GURPS roll notation (e.g. `3d-2`).**
+    ///
+    /// * Whitespaces are ignored.
+    /// * The die separator may be uppercase (e.g. `3D`).
+    /// * A missing type of die is read as a d6 (e.g. `3d` is read as `3d6`).
+    /// * A missing amount of dice is read as a single die (e.g. `d6` is read as `1d6`).
+    /// * Nothing may follow the modifier.
+    /// * Checks for validity of roll the same way [`Roll::parse_roll()`] does.
+    ///
+    /// # Examples
+    /// ```
+    /// use die_parser::Roll;
+    ///
+    /// let roll = Roll::parse_roll_from_gurps_notation("1d-1");
+    /// assert_eq!(roll, Ok(Roll::new(6, 1, -1)));
+    ///
+    /// let roll = Roll::parse_roll_from_gurps_notation("3d6+2");
+    /// assert_eq!(roll, Ok(Roll::new(6, 3, 2)));
+    /// ```
+    pub fn parse_roll_from_gurps_notation(input: &str) -> Result<Roll, RollError> {
+        // Remove whitespaces.
+        let whitespaceless = input.replace(' ', "");
+
+        // Parse amount of dice and the optional type of die.
+        let (remainder, (number_of_dice, number_of_sides)) = match parse_gurps_dice(&whitespaceless)
+        {
+            // A lone `d` gives neither the amount of dice nor the type of die.
+            Ok((_, (None, None))) | Err(_) => return Err(RollError::ParsingError),
+            Ok(v) => v,
+        };
+
+        // Parse the modifier
+        let (remainder, modifier) = parse_modifier(remainder)?;
+        if !remainder.is_empty() {
+            return Err(RollError::ParsingError);
+        }
+
+        let result = Roll::new(
+            number_of_sides.unwrap_or(6),
+            number_of_dice.unwrap_or(1),
+            modifier,
+        );
+        result.check_roll_validity(100)?;
+        Ok(result)
+    }
+}
+
+/// Tries to parse an optional amount of dice and an optional type of die (e.g. `3d`, `d6` or `3d6`).
+fn parse_gurps_dice(s: &str) -> IResult<&str, (Option<u16>, Option<u16>)> {
+    separated_pair(opt(parse_numbers), one_of("dD"), opt(parse_numbers))(s)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_roll_from_gurps_notation() {
+        let tests = [
+            ("3d", Ok(Roll::new(6, 3, 0))),
+            ("3d-2", Ok(Roll::new(6, 3, -2))),
+            ("1d-1", Ok(Roll::new(6, 1, -1))),
+            ("2d + 1", Ok(Roll::new(6, 2, 1))),
+            ("3d6-2", Ok(Roll::new(6, 3, -2))),
+            ("1d20", Ok(Roll::new(20, 1, 0))),
+            ("3D+1", Ok(Roll::new(6, 3, 1))),
+            ("d6", Ok(Roll::new(6, 1, 0))),
+            ("d", Err(RollError::ParsingError)),
+            ("3d6!", Err(RollError::ParsingError)),
+            ("3d+2 cutting", Err(RollError::ParsingError)),
+            ("3", Err(RollError::ParsingError)),
+            ("3d7", Err(RollError::DieTypeInvalid)),
+        ];
+
+        for (input, expected_output) in tests {
+            assert_eq!(Roll::parse_roll_from_gurps_notation(input), expected_output);
+        }
+    }
+}
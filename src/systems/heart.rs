@@ -0,0 +1,204 @@
+use crate::{Roll, RollError};
+
+/// The types of die used by Heart: The City Beneath.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DieType {
+    /// A four-sided die.
+    D4,
+    /// A six-sided die.
+    D6,
+    /// An eight-sided die.
+    D8,
+    /// A ten-sided die.
+    D10,
+    /// A twelve-sided die.
+    D12,
+}
+impl DieType {
+    /// Converts an amount of sides into a type of die, if Heart uses it.
+    pub fn from_sides(number_of_sides: u16) -> Option<Self> {
+        match number_of_sides {
+            4 => Some(Self::D4),
+            6 => Some(Self::D6),
+            8 => Some(Self::D8),
+            10 => Some(Self::D10),
+            12 => Some(Self::D12),
+            _ => None,
+        }
+    }
+
+    /// The amount of sides of the die.
+    pub fn sides(&self) -> u16 {
+        match self {
+            Self::D4 => 4,
+            Self::D6 => 6,
+            Self::D8 => 8,
+            Self::D10 => 10,
+            Self::D12 => 12,
+        }
+    }
+}
+
+/// A Heart dice pool of different dice, of which only the highest counts.
+#[derive(Debug, PartialEq)]
+pub struct HeartRoll {
+    /// The single dice in the pool.
+    pub dice: Vec<DieType>,
+    /// Whether the character is a novice, which removes the highest die.
+    pub novice: bool,
+    /// Whether the character has mastery, which cancels the novice rule.
+    pub mastery: bool,
+}
+impl HeartRoll {
+    /// Picks the highest result of the given results, which belong to the dice of the pool in order.
+    ///
+    /// * A novice without mastery has to remove the highest die, unless it is the only one.
+    /// * Returns `None` if there are no results.
+    pub fn result_from(&self, results: &[u16]) -> Option<HeartResult> {
+        let mut results: Vec<HeartResult> = self
+            .dice
+            .iter()
+            .zip(results)
+            .map(|(&die, &value)| HeartResult { value, die })
+            .collect();
+        results.sort_by_key(|result| result.value);
+
+        if self.novice && !self.mastery && results.len() > 1 {
+            results.pop();
+        }
+
+        results.pop()
+    }
+}
+#[cfg(feature = "rand")]
+impl HeartRoll {
+    /// Rolls every die of the pool and picks the highest result.
+    ///
+    /// Returns `None` if the pool has no dice.
+    pub fn roll<R: rand::Rng + ?Sized>(&self, rng: &mut R) -> Option<HeartResult> {
+        let results: Vec<u16> = self
+            .dice
+            .iter()
+            .map(|die| crate::rolling::roll_die(rng, die.sides()))
+            .collect();
+
+        self.result_from(&results)
+    }
+}
+
+/// The outcome of a [HeartRoll].
+#[derive(Debug, PartialEq)]
+pub struct HeartResult {
+    /// The result that counts.
+    pub value: u16,
+    /// The die that showed the result.
+    pub die: DieType,
+}
+
+impl Roll {
+    /// **Tries to parse input as a Heart dice pool (e.g. `d4+d6+d8`).**
+    ///
+    /// * Whitespaces are ignored.
+    /// * Dice are separated by `+`, the same way [`Roll::parse_roll_from_cortex_prime()`] reads them.
+    /// * Valid die types are: d4, d6, d8, d10, d12
+    /// * Enforces a limit of 100 dice per pool.
+    /// * The character is neither a novice nor has mastery, change the fields of [HeartRoll] to do so.
+    ///
+    /// # Examples
+    /// ```
+    /// use die_parser::Roll;
+    /// use die_parser::systems::{DieType, HeartResult};
+    ///
+    /// let heart_roll = Roll::parse_roll_from_heart("d4+d6+d8").unwrap();
+    /// assert_eq!(heart_roll.dice, vec![DieType::D4, DieType::D6, DieType::D8]);
+    ///
+    /// let result = heart_roll.result_from(&[3, 5, 2]);
+    /// assert_eq!(result, Some(HeartResult { value: 5, die: DieType::D6 }));
+    /// ```
+    pub fn parse_roll_from_heart(input: &str) -> Result<HeartRoll, RollError> {
+        // Heart shares the pool notation and die types with Cortex Prime.
+        let pool = Roll::parse_roll_from_cortex_prime(input)?;
+
+        let dice = pool
+            .dice
+            .iter()
+            .map(|die| DieType::from_sides(die.number_of_sides).ok_or(RollError::DieTypeInvalid))
+            .collect::<Result<_, _>>()?;
+
+        Ok(HeartRoll {
+            dice,
+            novice: false,
+            mastery: false,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_roll_from_heart() {
+        let tests = [
+            ("d4+d6+d8", Ok(vec![DieType::D4, DieType::D6, DieType::D8])),
+            (
+                "2d10 + d12",
+                Ok(vec![DieType::D10, DieType::D10, DieType::D12]),
+            ),
+            ("d6+d20", Err(RollError::DieTypeInvalid)),
+            ("d6+", Err(RollError::ParsingError)),
+            ("d4+d6!", Err(RollError::ParsingError)),
+            ("dF", Err(RollError::ParsingError)),
+        ];
+
+        for (input, expected_output) in tests {
+            let output = Roll::parse_roll_from_heart(input).map(|roll| roll.dice);
+            assert_eq!(output, expected_output);
+        }
+    }
+
+    #[test]
+    fn test_result_from() {
+        let results = [3, 5, 7];
+        let tests = [
+            ((false, false), Some((7, DieType::D8))),
+            ((true, false), Some((5, DieType::D6))),
+            ((true, true), Some((7, DieType::D8))),
+        ];
+
+        for ((novice, mastery), expected_output) in tests {
+            let heart_roll = HeartRoll {
+                dice: vec![DieType::D4, DieType::D6, DieType::D8],
+                novice,
+                mastery,
+            };
+            let expected_output = expected_output.map(|(value, die)| HeartResult { value, die });
+            assert_eq!(heart_roll.result_from(&results), expected_output);
+        }
+
+        // A novice keeps the only die of the pool.
+        let heart_roll = HeartRoll {
+            dice: vec![DieType::D10],
+            novice: true,
+            mastery: false,
+        };
+        let expected_output = Some(HeartResult {
+            value: 9,
+            die: DieType::D10,
+        });
+        assert_eq!(heart_roll.result_from(&[9]), expected_output);
+        assert_eq!(heart_roll.result_from(&[]), None);
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn test_roll() {
+        let mut rng = rand::thread_rng();
+        let heart_roll = Roll::parse_roll_from_heart("d4+d6").unwrap();
+
+        for _ in 0..100 {
+            let result = heart_roll.roll(&mut rng).unwrap();
+            assert!(result.value <= result.die.sides());
+        }
+    }
+}
@@ -0,0 +1,129 @@
+use crate::{Roll, RollError};
+
+/// A HeroQuest ability roll, rolling a d20 under a target number raised by masteries.
+#[derive(Debug, PartialEq)]
+pub struct HqRoll {
+    /// The parsed roll, which is always `1d20`.
+    pub roll: Roll,
+    /// The amount of masteries, marked with `m`.
+    pub masteries: u8,
+    /// The target number the roll has to be equal to or lower than.
+    pub target: u16,
+}
+impl HqRoll {
+    /// Determines the outcome of a rolled result against a target, bumped up once per mastery.
+    ///
+    /// | Result   | Outcome                     |
+    /// |----------|-----------------------------|
+    /// | 1        | [`HqResult::CritSuccess`]   |
+    /// | ≤ target | [`HqResult::Success`]       |
+    /// | 20       | [`HqResult::CritFailure`]   |
+    /// | > target | [`HqResult::Failure`]       |
+    pub fn apply_mastery(roll: u16, masteries: u8, target: u16) -> HqResult {
+        let result = if roll == 1 {
+            HqResult::CritSuccess
+        } else if roll <= target {
+            HqResult::Success
+        } else if roll == 20 {
+            HqResult::CritFailure
+        } else {
+            HqResult::Failure
+        };
+
+        (0..masteries).fold(result, |result, _| result.bump())
+    }
+}
+
+/// The outcome of a [HqRoll].
+#[derive(Debug, PartialEq)]
+pub enum HqResult {
+    /// A roll of 1.
+    CritSuccess,
+    /// A roll at or below the target.
+    Success,
+    /// A roll above the target.
+    Failure,
+    /// A roll of 20.
+    CritFailure,
+}
+impl HqResult {
+    /// Raises the outcome by one category, up to [`HqResult::CritSuccess`].
+    fn bump(self) -> Self {
+        match self {
+            Self::CritFailure => Self::Failure,
+            Self::Failure => Self::Success,
+            Self::Success | Self::CritSuccess => Self::CritSuccess,
+        }
+    }
+}
+
+impl Roll {
+    /// **Tries to parse input as a HeroQuest ability (e.g. `2m+10` or `15`).**
+    ///
+    /// * Whitespaces are ignored.
+    /// * The amount of masteries is marked with `m` and may be left out.
+    ///
+    /// # Examples
+    /// ```
+    /// use die_parser::Roll;
+    /// use die_parser::systems::{HqResult, HqRoll};
+    ///
+    /// let hq_roll = Roll::parse_roll_from_heroquest_notation("2m+10").unwrap();
+    /// assert_eq!((hq_roll.masteries, hq_roll.target), (2, 10));
+    /// assert_eq!(HqRoll::apply_mastery(15, 1, 10), HqResult::Success);
+    /// ```
+    pub fn parse_roll_from_heroquest_notation(input: &str) -> Result<HqRoll, RollError> {
+        let whitespaceless = input.replace(' ', "");
+
+        let (masteries, target) = match whitespaceless.split_once("m+") {
+            Some((masteries, target)) => (masteries.parse(), target),
+            None => (Ok(0), whitespaceless.as_str()),
+        };
+
+        Ok(HqRoll {
+            roll: Roll::new(20, 1, 0),
+            masteries: masteries.map_err(|_| RollError::ParsingError)?,
+            target: target.parse().map_err(|_| RollError::ParsingError)?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_roll_from_heroquest_notation() {
+        let tests = [
+            ("2m+10", Ok((2, 10))),
+            ("1m + 5", Ok((1, 5))),
+            ("15", Ok((0, 15))),
+            ("m+10", Err(RollError::ParsingError)),
+            ("2m", Err(RollError::ParsingError)),
+            ("1d20", Err(RollError::ParsingError)),
+        ];
+
+        for (input, expected_output) in tests {
+            let output = Roll::parse_roll_from_heroquest_notation(input)
+                .map(|roll| (roll.masteries, roll.target));
+            assert_eq!(output, expected_output);
+        }
+    }
+
+    #[test]
+    fn test_apply_mastery() {
+        let tests = [
+            ((1, 0), HqResult::CritSuccess),
+            ((8, 0), HqResult::Success),
+            ((15, 0), HqResult::Failure),
+            ((20, 0), HqResult::CritFailure),
+            ((8, 1), HqResult::CritSuccess),
+            ((20, 1), HqResult::Failure),
+            ((20, 3), HqResult::CritSuccess),
+        ];
+
+        for ((roll, masteries), expected_output) in tests {
+            assert_eq!(HqRoll::apply_mastery(roll, masteries, 10), expected_output);
+        }
+    }
+}
@@ -0,0 +1,106 @@
+use crate::{Roll, RollError};
+
+/// An Into the Odd roll, either d6s for items or a d20 save.
+#[derive(Debug, PartialEq)]
+pub struct ItoRoll {
+    /// The parsed roll, which uses either d6s or a single d20.
+    pub roll: Roll,
+    /// Whether the damage is dealt directly, marked with a `direct` suffix.
+    pub direct_damage: bool,
+}
+
+impl Roll {
+    /// **Tries to parse input as an Into the Odd roll (e.g. `d6`, `2d6 direct` or `d20`).**
+    ///
+    /// * Whitespaces are ignored.
+    /// * Items roll d6s, saves roll exactly `1d20`.
+    /// * Direct Damage is marked with a `direct` suffix and only applies to d6s.
+    ///
+    /// # Examples
+    /// ```
+    /// use die_parser::Roll;
+    ///
+    /// let ito_roll = Roll::parse_roll_from_into_the_odd_notation("d6 direct").unwrap();
+    /// assert_eq!(ito_roll.roll, Roll::new(6, 1, 0));
+    /// assert!(ito_roll.direct_damage);
+    /// ```
+    pub fn parse_roll_from_into_the_odd_notation(input: &str) -> Result<ItoRoll, RollError> {
+        let whitespaceless = input.replace(' ', "");
+
+        let (notation, direct_damage) = match whitespaceless.strip_suffix("direct") {
+            Some(notation) => (notation, true),
+            None => (whitespaceless.as_str(), false),
+        };
+
+        let roll = super::parse_plain_roll(notation)?;
+
+        let is_valid = match (roll.number_of_sides, roll.number_of_dice) {
+            (6, _) => true,
+            (20, 1) => !direct_damage,
+            (20, _) => false,
+            _ => return Err(RollError::DieTypeInvalid),
+        };
+
+        if !is_valid || roll.modifier != 0 {
+            return Err(RollError::ParsingError);
+        }
+
+        Ok(ItoRoll {
+            roll,
+            direct_damage,
+        })
+    }
+
+    /// Checks whether a rolled save succeeds, which happens at or below the attribute.
+    ///
+    /// # Example
+    /// ```
+    /// use die_parser::Roll;
+    ///
+    /// assert!(Roll::ito_save(12, 12));
+    /// assert!(!Roll::ito_save(12, 13));
+    /// ```
+    pub fn ito_save(target_attr: u8, result: u16) -> bool {
+        result <= u16::from(target_attr)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_roll_from_into_the_odd_notation() {
+        let tests = [
+            ("d6", Ok((Roll::new(6, 1, 0), false))),
+            ("2d6 direct", Ok((Roll::new(6, 2, 0), true))),
+            ("1d20", Ok((Roll::new(20, 1, 0), false))),
+            ("d20direct", Err(RollError::ParsingError)),
+            ("2d20", Err(RollError::ParsingError)),
+            ("d6+1", Err(RollError::ParsingError)),
+            ("2d6kh1", Err(RollError::ParsingError)),
+            ("d6!", Err(RollError::ParsingError)),
+            ("d8", Err(RollError::DieTypeInvalid)),
+        ];
+
+        for (input, expected_output) in tests {
+            let output = Roll::parse_roll_from_into_the_odd_notation(input)
+                .map(|ito_roll| (ito_roll.roll, ito_roll.direct_damage));
+            assert_eq!(output, expected_output);
+        }
+    }
+
+    #[test]
+    fn test_ito_save() {
+        let tests = [
+            (1, 10, true),
+            (10, 10, true),
+            (11, 10, false),
+            (20, 0, false),
+        ];
+
+        for (result, target_attr, expected_output) in tests {
+            assert_eq!(Roll::ito_save(target_attr, result), expected_output);
+        }
+    }
+}
@@ -0,0 +1,132 @@
+use crate::{Roll, RollError};
+
+/// The highest action score possible in Ironsworn.
+const MAX_ACTION_SCORE: i32 = 10;
+
+/// An Ironsworn action roll against two challenge dice.
+#[derive(Debug, PartialEq)]
+pub struct IronswornRoll {
+    /// The action die, which is always a single d6.
+    pub action_die: Roll,
+    /// The stat added to the action die.
+    pub stat: i32,
+    /// The challenge dice, which are always two d10s.
+    pub challenge_dice: Roll,
+}
+impl IronswornRoll {
+    /// Determines the outcome of an action score (action die plus stat) against both challenge dice.
+    ///
+    /// The action score is capped at 10 and has to beat a challenge die to count.
+    pub fn outcome(action_score: i32, challenge_results: [u16; 2]) -> IronswornOutcome {
+        let action_score = action_score.min(MAX_ACTION_SCORE);
+        let beaten = challenge_results
+            .iter()
+            .filter(|&&result| action_score > result as i32)
+            .count();
+
+        match beaten {
+            2 => IronswornOutcome::Hit,
+            1 => IronswornOutcome::WeakHit,
+            _ => IronswornOutcome::Miss,
+        }
+    }
+}
+#[cfg(feature = "rand")]
+impl IronswornRoll {
+    /// Rolls the action die and both challenge dice and determines the outcome.
+    pub fn roll<R: rand::Rng + ?Sized>(&self, rng: &mut R) -> IronswornOutcome {
+        let action_score = (crate::rolling::roll_die(rng, 6) as i32).saturating_add(self.stat);
+        let challenge_results = [
+            crate::rolling::roll_die(rng, 10),
+            crate::rolling::roll_die(rng, 10),
+        ];
+
+        IronswornRoll::outcome(action_score, challenge_results)
+    }
+}
+
+/// The outcome of an [IronswornRoll].
+#[derive(Debug, PartialEq)]
+pub enum IronswornOutcome {
+    /// The action score beat both challenge dice.
+    Hit,
+    /// The action score beat one of the challenge dice.
+    WeakHit,
+    /// The action score beat neither challenge die.
+    Miss,
+}
+
+impl Roll {
+    /// **Tries to parse input as an Ironsworn action roll (e.g. `1d6+2 vs 2d10`).**
+    ///
+    /// * Whitespaces are ignored.
+    /// * The action die has to be a single d6, its modifier is the stat.
+    /// * The challenge dice have to be two d10s.
+    ///
+    /// # Examples
+    /// ```
+    /// use die_parser::Roll;
+    ///
+    /// let ironsworn_roll = Roll::parse_roll_from_ironsworn("1d6+2 vs 2d10").unwrap();
+    /// assert_eq!(ironsworn_roll.action_die, Roll::new(6, 1, 0));
+    /// assert_eq!(ironsworn_roll.stat, 2);
+    /// ```
+    pub fn parse_roll_from_ironsworn(input: &str) -> Result<IronswornRoll, RollError> {
+        let (action, challenge) = input.split_once("vs").ok_or(RollError::ParsingError)?;
+
+        let action = super::parse_plain_roll(action)?;
+        let challenge_dice = super::parse_pool(challenge, 10)?;
+
+        if action.number_of_sides != 6 {
+            return Err(RollError::DieTypeInvalid);
+        } else if action.number_of_dice != 1 || challenge_dice.number_of_dice != 2 {
+            return Err(RollError::ParsingError);
+        }
+
+        Ok(IronswornRoll {
+            action_die: Roll::new(6, 1, 0),
+            stat: action.modifier,
+            challenge_dice,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_roll_from_ironsworn() {
+        let tests = [
+            ("1d6+2 vs 2d10", Ok(2)),
+            ("1d6vs2d10", Ok(0)),
+            ("1d6+3", Err(RollError::ParsingError)),
+            ("1d6!+2 vs 2d10", Err(RollError::ParsingError)),
+            ("2d6+2 vs 2d10", Err(RollError::ParsingError)),
+            ("1d6+2 vs 3d10", Err(RollError::ParsingError)),
+            ("1d8+2 vs 2d10", Err(RollError::DieTypeInvalid)),
+            ("1d6+2 vs 2d12", Err(RollError::DieTypeInvalid)),
+        ];
+
+        for (input, expected_output) in tests {
+            let output = Roll::parse_roll_from_ironsworn(input).map(|roll| roll.stat);
+            assert_eq!(output, expected_output);
+        }
+    }
+
+    #[test]
+    fn test_outcome() {
+        let tests = [
+            (7, [3, 6], IronswornOutcome::Hit),
+            (7, [7, 2], IronswornOutcome::WeakHit),
+            (4, [5, 9], IronswornOutcome::Miss),
+            // The action score is capped at 10.
+            (12, [10, 1], IronswornOutcome::WeakHit),
+        ];
+
+        for (action_score, challenge_results, expected_output) in tests {
+            let output = IronswornRoll::outcome(action_score, challenge_results);
+            assert_eq!(output, expected_output);
+        }
+    }
+}
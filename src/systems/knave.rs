@@ -0,0 +1,91 @@
+use nom::bytes::complete::tag_no_case;
+use nom::sequence::terminated;
+use nom::IResult;
+
+use crate::{parse_modifier, parse_numbers, Roll, RollError};
+
+impl Roll {
+    /// **Tries to parse input as Knave hit dice notation (e.g. `3HD+1`).**
+    ///
+    /// * Whitespaces are ignored and `HD` may be written in either case.
+    /// * Hit dice are d8s, so `3HD` is read as `3d8`.
+    /// * Enforces a limit of 100 dice per roll.
+    ///
+    /// # Examples
+    /// ```
+    /// use die_parser::Roll;
+    ///
+    /// let roll = Roll::parse_roll_from_knave_notation("3HD+1");
+    /// assert_eq!(roll, Ok(Roll::new(8, 3, 1)));
+    /// ```
+    pub fn parse_roll_from_knave_notation(input: &str) -> Result<Roll, RollError> {
+        // Remove whitespaces.
+        let whitespaceless = input.replace(' ', "");
+
+        // Parse amount of hit dice.
+        let (remainder, number_of_dice) = match parse_hit_dice(&whitespaceless) {
+            Ok(v) => v,
+            Err(_) => return Err(RollError::ParsingError),
+        };
+
+        // Parse the modifier
+        let (_, modifier) = parse_modifier(remainder)?;
+
+        let result = Roll::new(8, number_of_dice, modifier);
+        result.check_roll_validity(100)?;
+        Ok(result)
+    }
+
+    /// Checks whether the input is written in hit dice notation (e.g. `3HD`), regardless of its validity.
+    ///
+    /// # Example
+    /// ```
+    /// use die_parser::Roll;
+    ///
+    /// assert!(Roll::is_hd_notation("3HD+1"));
+    /// assert!(!Roll::is_hd_notation("3d8+1"));
+    /// ```
+    pub fn is_hd_notation(input: &str) -> bool {
+        parse_hit_dice(&input.replace(' ', "")).is_ok()
+    }
+}
+
+/// Tries to parse the amount of hit dice (e.g. `3HD`).
+fn parse_hit_dice(s: &str) -> IResult<&str, u16> {
+    terminated(parse_numbers, tag_no_case("HD"))(s)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_roll_from_knave_notation() {
+        let tests = [
+            ("3HD", Ok(Roll::new(8, 3, 0))),
+            ("3HD+1", Ok(Roll::new(8, 3, 1))),
+            ("2 hd - 2", Ok(Roll::new(8, 2, -2))),
+            ("0HD", Err(RollError::NoDiceToRoll)),
+            ("HD", Err(RollError::ParsingError)),
+            ("3d8", Err(RollError::ParsingError)),
+        ];
+
+        for (input, expected_output) in tests {
+            assert_eq!(Roll::parse_roll_from_knave_notation(input), expected_output);
+        }
+    }
+
+    #[test]
+    fn test_is_hd_notation() {
+        let tests = [
+            ("3HD", true),
+            ("0hd+2", true),
+            ("3d8", false),
+            ("HD", false),
+        ];
+
+        for (input, expected_output) in tests {
+            assert_eq!(Roll::is_hd_notation(input), expected_output);
+        }
+    }
+}
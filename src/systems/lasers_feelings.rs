@@ -0,0 +1,80 @@
+use crate::{Roll, RollError};
+
+impl Roll {
+    /// **Tries to parse input as a Lasers & Feelings dice pool (e.g. `4d6`).**
+    ///
+    /// * Only d6s without a modifier are allowed.
+    /// * Enforces a limit of 100 dice per pool.
+    ///
+    /// # Examples
+    /// ```
+    /// use die_parser::Roll;
+    ///
+    /// let roll = Roll::parse_roll_from_lasers_feelings("3d6");
+    /// assert_eq!(roll, Ok(Roll::new(6, 3, 0)));
+    /// ```
+    pub fn parse_roll_from_lasers_feelings(input: &str) -> Result<Roll, RollError> {
+        super::parse_pool(input, 6)
+    }
+
+    /// Counts the dice that rolled at or below `stat`, as done for roll-under mechanics.
+    ///
+    /// # Example
+    /// ```
+    /// use die_parser::Roll;
+    ///
+    /// assert_eq!(Roll::count_under(3, &[1, 3, 4, 6]), 2);
+    /// ```
+    pub fn count_under(stat: u8, rolls: &[u16]) -> u32 {
+        rolls.iter().filter(|&&roll| roll <= stat as u16).count() as u32
+    }
+
+    /// Counts the dice that rolled exactly `stat`, which are "risky" successes.
+    ///
+    /// # Example
+    /// ```
+    /// use die_parser::Roll;
+    ///
+    /// assert_eq!(Roll::count_risky(3, &[1, 3, 4, 3]), 2);
+    /// ```
+    pub fn count_risky(stat: u8, rolls: &[u16]) -> u32 {
+        rolls.iter().filter(|&&roll| roll == stat as u16).count() as u32
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_roll_from_lasers_feelings() {
+        let tests = [
+            ("4d6", Ok(Roll::new(6, 4, 0))),
+            ("1d6", Ok(Roll::new(6, 1, 0))),
+            ("4d6+1", Err(RollError::ParsingError)),
+            ("4d8", Err(RollError::DieTypeInvalid)),
+        ];
+
+        for (input, expected_output) in tests {
+            assert_eq!(
+                Roll::parse_roll_from_lasers_feelings(input),
+                expected_output
+            );
+        }
+    }
+
+    #[test]
+    fn test_count_under_and_risky() {
+        let tests = [
+            (4, vec![1, 4, 5, 6], 2, 1),
+            (2, vec![3, 4, 5], 0, 0),
+            (5, vec![5, 5, 1], 3, 2),
+            (3, vec![], 0, 0),
+        ];
+
+        for (stat, rolls, expected_under, expected_risky) in tests {
+            assert_eq!(Roll::count_under(stat, &rolls), expected_under);
+            assert_eq!(Roll::count_risky(stat, &rolls), expected_risky);
+        }
+    }
+}
@@ -0,0 +1,254 @@
+use nom::character::complete::{anychar, char};
+use nom::multi::separated_list1;
+use nom::sequence::tuple;
+
+use crate::{parse_numbers, Roll, RollError};
+
+/// The most dice a [L5rRoll] may keep, to stop explosions from going on forever.
+#[cfg(feature = "rand")]
+const MAX_KEPT: usize = 100;
+
+/// A Legend of the Five Rings roll, made of a pool of ring dice and a pool of skill dice.
+#[derive(Debug, PartialEq)]
+pub struct L5rRoll {
+    /// The ring dice, d6s marked with `r`.
+    pub ring: Roll,
+    /// The skill dice, d12s marked with `s`.
+    pub skill: Roll,
+}
+impl L5rRoll {
+    /// Keeps the best `n` of the given dice, preferring explosive successes, then successes, then opportunities.
+    ///
+    /// Faces without strife are preferred over equal faces with strife.
+    pub fn keep(mut dice: Vec<L5rDie>, n: usize) -> Vec<L5rDie> {
+        dice.sort_by_key(|die| {
+            std::cmp::Reverse((die.explosive, die.success, die.opportunity, !die.strife))
+        });
+        dice.truncate(n);
+        dice
+    }
+}
+#[cfg(feature = "rand")]
+impl L5rRoll {
+    /// Rolls both pools and keeps as many dice as there are ring dice, against a target number.
+    ///
+    /// Every kept explosive success adds another kept die of the same type.
+    pub fn roll<R: rand::Rng + ?Sized>(&self, rng: &mut R, tn: u8) -> L5rResult {
+        let ring = crate::rolling::roll_dice(rng, self.ring.number_of_dice, 6);
+        let skill = crate::rolling::roll_dice(rng, self.skill.number_of_dice, 12);
+
+        let dice = ring
+            .into_iter()
+            .map(L5rDie::ring)
+            .chain(skill.into_iter().map(L5rDie::skill))
+            .collect();
+        let mut kept = Self::keep(dice, self.ring.number_of_dice.into());
+
+        // Explosions are added to the end, so they may explode as well.
+        let mut i = 0;
+        while i < kept.len() && kept.len() < MAX_KEPT {
+            if kept[i].explosive {
+                kept.push(match kept[i].is_skill_die {
+                    false => L5rDie::ring(crate::rolling::roll_die(rng, 6)),
+                    true => L5rDie::skill(crate::rolling::roll_die(rng, 12)),
+                });
+            }
+            i += 1;
+        }
+
+        L5rResult::new(kept, tn)
+    }
+}
+
+/// A single rolled die of a [L5rRoll] and the symbols it shows.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct L5rDie {
+    /// Whether the die is a skill die rather than a ring die.
+    pub is_skill_die: bool,
+    /// Whether the die shows a success.
+    pub success: bool,
+    /// Whether the success is explosive.
+    pub explosive: bool,
+    /// Whether the die shows an opportunity.
+    pub opportunity: bool,
+    /// Whether the die shows strife.
+    pub strife: bool,
+}
+impl L5rDie {
+    /// Reads the symbols of a ring die from its result.
+    pub fn ring(result: u16) -> Self {
+        let (success, explosive, opportunity, strife) = match result {
+            2 => (false, false, true, true),
+            3 => (false, false, true, false),
+            4 => (true, false, false, true),
+            5 => (true, false, false, false),
+            6 => (true, true, false, true),
+            _ => (false, false, false, false),
+        };
+
+        Self {
+            is_skill_die: false,
+            success,
+            explosive,
+            opportunity,
+            strife,
+        }
+    }
+
+    /// Reads the symbols of a skill die from its result.
+    pub fn skill(result: u16) -> Self {
+        let (success, explosive, opportunity, strife) = match result {
+            3..=5 => (false, false, true, false),
+            6..=7 => (true, false, false, true),
+            8..=9 => (true, false, false, false),
+            10 => (true, false, true, false),
+            11 => (true, true, false, true),
+            12 => (true, true, false, false),
+            _ => (false, false, false, false),
+        };
+
+        Self {
+            is_skill_die: true,
+            success,
+            explosive,
+            opportunity,
+            strife,
+        }
+    }
+}
+
+/// The outcome of a [L5rRoll].
+#[derive(Debug, PartialEq)]
+pub struct L5rResult {
+    /// The dice that were kept, including those added by explosions.
+    pub kept: Vec<L5rDie>,
+    /// Whether the kept dice show at least as many successes as the target number.
+    pub passed: bool,
+}
+impl L5rResult {
+    /// Evaluates the kept dice against a target number.
+    pub fn new(kept: Vec<L5rDie>, tn: u8) -> Self {
+        let successes = kept.iter().filter(|die| die.success).count();
+
+        Self {
+            passed: successes >= tn.into(),
+            kept,
+        }
+    }
+}
+
+impl Roll {
+    /// **Tries to parse input as Legend of the Five Rings dice pools (e.g. `2r+3s`).**
+    ///
+    /// * Whitespaces are ignored.
+    /// * Ring dice are marked with `r` and are always d6s.
+    /// * Skill dice are marked with `s` and are always d12s.
+    ///     * Either pool may be left out, but neither may be given twice.
+    /// * Enforces a limit of 100 dice per pool.
+    ///
+    /// # Examples
+    /// ```
+    /// use die_parser::Roll;
+    ///
+    /// let l5r_roll = Roll::parse_roll_from_legend5r("2r+3s").unwrap();
+    /// assert_eq!(l5r_roll.ring, Roll::new(6, 2, 0));
+    /// assert_eq!(l5r_roll.skill, Roll::new(12, 3, 0));
+    /// ```
+    pub fn parse_roll_from_legend5r(input: &str) -> Result<L5rRoll, RollError> {
+        // Remove whitespaces.
+        let whitespaceless = input.replace(' ', "");
+
+        let pools =
+            match separated_list1(char('+'), tuple((parse_numbers, anychar)))(&whitespaceless) {
+                Ok(("", pools)) => pools,
+                _ => return Err(RollError::ParsingError),
+            };
+
+        let mut ring = None;
+        let mut skill = None;
+
+        for (number_of_dice, pool_type) in pools {
+            let (slot, number_of_sides) = match pool_type {
+                'r' => (&mut ring, 6),
+                's' => (&mut skill, 12),
+                _ => return Err(RollError::ParsingError),
+            };
+
+            let pool = Roll::new(number_of_sides, number_of_dice, 0);
+            pool.check_roll_validity(100)?;
+
+            if slot.replace(pool).is_some() {
+                return Err(RollError::ParsingError);
+            }
+        }
+
+        Ok(L5rRoll {
+            ring: ring.unwrap_or(Roll::new(6, 0, 0)),
+            skill: skill.unwrap_or(Roll::new(12, 0, 0)),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_roll_from_legend5r() {
+        let tests = [
+            ("2r+3s", Ok((2, 3))),
+            ("3s + 2r", Ok((2, 3))),
+            ("3r", Ok((3, 0))),
+            ("2r+1r", Err(RollError::ParsingError)),
+            ("2r+3x", Err(RollError::ParsingError)),
+            ("101s", Err(RollError::DiceExceedLimit)),
+        ];
+
+        for (input, expected_output) in tests {
+            let output = Roll::parse_roll_from_legend5r(input)
+                .map(|roll| (roll.ring.number_of_dice, roll.skill.number_of_dice));
+            assert_eq!(output, expected_output);
+        }
+    }
+
+    #[test]
+    fn test_keep() {
+        let tests = [
+            (
+                vec![L5rDie::ring(1), L5rDie::ring(6), L5rDie::skill(8)],
+                vec![L5rDie::ring(6), L5rDie::skill(8)],
+            ),
+            (
+                vec![L5rDie::ring(4), L5rDie::ring(5), L5rDie::ring(3)],
+                vec![L5rDie::ring(5), L5rDie::ring(4)],
+            ),
+            (vec![L5rDie::skill(1)], vec![L5rDie::skill(1)]),
+        ];
+
+        for (dice, expected_output) in tests {
+            assert_eq!(L5rRoll::keep(dice, 2), expected_output);
+        }
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn test_roll() {
+        let mut rng = rand::thread_rng();
+        let l5r_roll = Roll::parse_roll_from_legend5r("2r+3s").unwrap();
+
+        for _ in 0..100 {
+            let result = l5r_roll.roll(&mut rng, 1);
+            let explosions = result.kept.iter().filter(|die| die.explosive).count();
+            assert!(result.kept.len() >= 2);
+            assert!(result.kept.len() <= 2 + explosions);
+        }
+    }
+
+    #[test]
+    fn test_l5r_result() {
+        let kept = vec![L5rDie::ring(5), L5rDie::skill(10), L5rDie::skill(3)];
+
+        assert!(L5rResult::new(kept.clone(), 2).passed);
+        assert!(!L5rResult::new(kept, 3).passed);
+    }
+}
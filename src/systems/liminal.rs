@@ -0,0 +1,145 @@
+use crate::{Roll, RollError};
+
+/// A Liminal dice pool, in which every die is compared to a difficulty.
+#[derive(Debug, PartialEq)]
+pub struct LiminalRoll {
+    /// The pool of d6s to be rolled.
+    pub pool: Roll,
+}
+#[cfg(feature = "rand")]
+impl LiminalRoll {
+    /// Rolls the pool and counts successes and fumbles against the given difficulty.
+    pub fn roll<R: rand::Rng + ?Sized>(&self, rng: &mut R, diff: u8) -> LiminalResult {
+        self.roll_with_push(rng, diff, false)
+    }
+
+    /// Rolls the pool, rerolling all failed dice once if `push` is requested.
+    ///
+    /// The fumbles of the rerolled dice are added to those of the first roll,
+    /// so pushing risks more fumbles.
+    pub fn roll_with_push<R: rand::Rng + ?Sized>(
+        &self,
+        rng: &mut R,
+        diff: u8,
+        push: bool,
+    ) -> LiminalResult {
+        let results = crate::rolling::roll_dice(rng, self.pool.number_of_dice, 6);
+        let mut result = LiminalResult::from_results(&results, diff);
+
+        let rerolls = self.pool.number_of_dice - result.successes;
+        if push && rerolls > 0 {
+            let rerolled = crate::rolling::roll_dice(rng, rerolls, 6);
+            let pushed_result = LiminalResult::from_results(&rerolled, diff);
+
+            result.successes += pushed_result.successes;
+            result.fumbles += pushed_result.fumbles;
+            result.pushed = true;
+        }
+
+        result
+    }
+}
+
+/// The outcome of a [LiminalRoll].
+#[derive(Debug, PartialEq)]
+pub struct LiminalResult {
+    /// How many dice met or exceeded the difficulty.
+    pub successes: u16,
+    /// How many dice showed a 1, including those of a push.
+    pub fumbles: u16,
+    /// Whether the roll was pushed.
+    pub pushed: bool,
+}
+impl LiminalResult {
+    /// Evaluates the results of the single dice of an unpushed pool against a difficulty.
+    ///
+    /// A 1 is never a success, even against a difficulty of 1 or less.
+    pub fn from_results(results: &[u16], diff: u8) -> Self {
+        let successes = results
+            .iter()
+            .filter(|&&result| result != 1 && result >= u16::from(diff))
+            .count();
+
+        Self {
+            successes: successes as u16,
+            fumbles: results.iter().filter(|&&result| result == 1).count() as u16,
+            pushed: false,
+        }
+    }
+}
+
+impl Roll {
+    /// **Tries to parse input as a Liminal dice pool (e.g. `3d6`).**
+    ///
+    /// * Whitespaces are ignored.
+    /// * Only d6s without a modifier are allowed.
+    /// * Enforces a limit of 100 dice per pool.
+    ///
+    /// # Examples
+    /// ```
+    /// use die_parser::Roll;
+    ///
+    /// let liminal_roll = Roll::parse_roll_from_liminal("3d6").unwrap();
+    /// assert_eq!(liminal_roll.pool, Roll::new(6, 3, 0));
+    /// ```
+    pub fn parse_roll_from_liminal(input: &str) -> Result<LiminalRoll, RollError> {
+        let pool = super::parse_pool(input, 6)?;
+
+        Ok(LiminalRoll { pool })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_roll_from_liminal() {
+        let tests = [
+            ("3d6", Ok(Roll::new(6, 3, 0))),
+            ("3d6+2", Err(RollError::ParsingError)),
+            ("3d10", Err(RollError::DieTypeInvalid)),
+            ("0d6", Err(RollError::NoDiceToRoll)),
+        ];
+
+        for (input, expected_output) in tests {
+            let output = Roll::parse_roll_from_liminal(input).map(|roll| roll.pool);
+            assert_eq!(output, expected_output);
+        }
+    }
+
+    #[test]
+    fn test_liminal_result() {
+        let tests = [
+            ((vec![5, 4, 1], 4), (2, 1)),
+            ((vec![3, 2, 6], 5), (1, 0)),
+            ((vec![1, 1, 2], 1), (1, 2)),
+            ((vec![], 4), (0, 0)),
+        ];
+
+        for ((results, diff), (successes, fumbles)) in tests {
+            let expected_output = LiminalResult {
+                successes,
+                fumbles,
+                pushed: false,
+            };
+            assert_eq!(LiminalResult::from_results(&results, diff), expected_output);
+        }
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn test_roll_with_push() {
+        let mut rng = rand::thread_rng();
+        let liminal_roll = Roll::parse_roll_from_liminal("4d6").unwrap();
+
+        for _ in 0..100 {
+            let result = liminal_roll.roll_with_push(&mut rng, 4, true);
+            assert!(result.successes <= 4);
+            // Failed dice are rolled twice, so they may fumble twice.
+            assert!(result.fumbles <= 8);
+            assert!(result.pushed || result.successes == 4);
+            assert!(!liminal_roll.roll(&mut rng, 4).pushed);
+        }
+    }
+}
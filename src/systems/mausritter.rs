@@ -0,0 +1,86 @@
+use crate::{Roll, RollError};
+
+/// The types of die a usage die steps through, from largest to smallest.
+const USAGE_DIE_CHAIN: [u16; 5] = [12, 10, 8, 6, 4];
+
+/// A Mausritter usage die, which degrades to the next smaller die when rolling low.
+#[derive(Debug, PartialEq)]
+pub struct UsageDie {
+    /// The die currently used.
+    pub die: Roll,
+}
+impl UsageDie {
+    /// Checks whether the given result makes the usage die degrade (a 1 or 2).
+    pub fn degrades_on(result: u16) -> bool {
+        (1..=2).contains(&result)
+    }
+
+    /// Returns the next smaller standard die, or `None` if a d4 is used up.
+    pub fn degrade(&self) -> Option<Roll> {
+        super::next_die_in_chain(&USAGE_DIE_CHAIN, &self.die)
+    }
+}
+
+impl Roll {
+    /// **Tries to parse input as a Mausritter usage die (e.g. `d12u`).**
+    ///
+    /// * Whitespaces are ignored.
+    /// * The die has to be suffixed with `u` and may be written with or without `1`.
+    /// * Valid usage dice are: d4, d6, d8, d10, d12
+    ///
+    /// # Examples
+    /// ```
+    /// use die_parser::Roll;
+    ///
+    /// let usage_die = Roll::parse_roll_from_mausritter("d12u").unwrap();
+    /// assert_eq!(usage_die.die, Roll::new(12, 1, 0));
+    /// assert_eq!(usage_die.degrade(), Some(Roll::new(10, 1, 0)));
+    /// ```
+    pub fn parse_roll_from_mausritter(input: &str) -> Result<UsageDie, RollError> {
+        let die = super::parse_suffixed_die(input, "u", &USAGE_DIE_CHAIN)?;
+
+        Ok(UsageDie { die })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_roll_from_mausritter() {
+        let tests = [
+            ("d12u", Ok(Roll::new(12, 1, 0))),
+            ("1d6u", Ok(Roll::new(6, 1, 0))),
+            ("d8", Err(RollError::ParsingError)),
+            ("2d6u", Err(RollError::ParsingError)),
+            ("d6+1u", Err(RollError::ParsingError)),
+            ("d20u", Err(RollError::DieTypeInvalid)),
+        ];
+
+        for (input, expected_output) in tests {
+            let output = Roll::parse_roll_from_mausritter(input).map(|usage_die| usage_die.die);
+            assert_eq!(output, expected_output);
+        }
+    }
+
+    #[test]
+    fn test_degrade() {
+        let tests = [
+            (12, Some(Roll::new(10, 1, 0))),
+            (8, Some(Roll::new(6, 1, 0))),
+            (6, Some(Roll::new(4, 1, 0))),
+            (4, None),
+        ];
+
+        for (sides, expected_output) in tests {
+            let usage_die = UsageDie {
+                die: Roll::new(sides, 1, 0),
+            };
+            assert_eq!(usage_die.degrade(), expected_output);
+        }
+
+        assert!(UsageDie::degrades_on(2));
+        assert!(!UsageDie::degrades_on(3));
+    }
+}
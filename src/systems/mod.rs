@@ -0,0 +1,186 @@
+//! Parsers and resolution rules for specific tabletop game systems.
+
+use crate::{DieKind, Roll, RollError};
+
+mod alien;
+mod blades;
+mod brp;
+mod burning_wheel;
+mod cairn;
+mod call_of_cthulhu;
+mod conan;
+mod coriolis;
+mod cortex_prime;
+mod cypher;
+mod dcc;
+mod degenesis;
+mod dragonbane;
+mod dungeon_world;
+mod fate;
+mod forbidden_lands;
+mod forged_in_dark;
+mod fortune;
+mod genesys;
+mod gurps;
+mod heart;
+mod heroquest;
+mod into_the_odd;
+mod ironsworn;
+mod knave;
+mod lasers_feelings;
+mod legend5r;
+mod liminal;
+mod mausritter;
+mod mothership;
+mod mouse_guard;
+mod numenera;
+mod nwod;
+mod one_roll;
+mod open_d6;
+mod pathfinder;
+mod pbta;
+mod pendragon;
+mod runequest;
+mod savage_worlds;
+mod shadowrun;
+mod spire;
+mod star_wars_ffg;
+mod symbaroum;
+mod torchbearer;
+mod traveller;
+mod troika;
+mod twilight2000;
+mod ultraviolet_grasslands;
+mod vaesen;
+mod vampire;
+mod warhammer;
+mod white_hack;
+mod wrath_glory;
+mod year_zero;
+mod zweihander;
+
+pub use alien::{AlienResult, AlienRoll};
+pub use blades::{BladesResult, BladesRoll};
+pub use brp::{BrpResult, BrpRoll};
+pub use burning_wheel::{BwRoll, BwShade};
+pub use cairn::{CairnHd, CairnRoll};
+pub use call_of_cthulhu::CocRoll;
+pub use conan::Conan2d20;
+pub use coriolis::{CoriolisResult, CoriolisRoll};
+pub use cortex_prime::CortexPool;
+pub use dcc::DccFunnel;
+pub use degenesis::{DegResult, DegRoll};
+pub use dragonbane::{DragonbaneResult, DragonbaneRoll};
+pub use dungeon_world::{DwOutcome, DwRoll};
+pub use fate::FateRoll;
+pub use forbidden_lands::{ForbiddenLandsPool, ForbiddenLandsResult, ForbiddenLandsRoll};
+pub use forged_in_dark::FitdRoll;
+pub use genesys::{GenesysDice, GenesysResult};
+pub use heart::{DieType, HeartResult, HeartRoll};
+pub use heroquest::{HqResult, HqRoll};
+pub use into_the_odd::ItoRoll;
+pub use ironsworn::{IronswornOutcome, IronswornRoll};
+pub use legend5r::{L5rDie, L5rResult, L5rRoll};
+pub use liminal::{LiminalResult, LiminalRoll};
+pub use mausritter::UsageDie;
+pub use mothership::{MotherStress, MothershipRoll};
+pub use mouse_guard::{MgResult, MgRoll};
+pub use numenera::{NumenEffect, NumenRoll};
+pub use nwod::NwodRoll;
+pub use one_roll::{OreRoll, OreSet};
+pub use open_d6::OpenD6Roll;
+pub use pbta::{PbtaOutcome, PbtaRoll};
+pub use pendragon::{PenResult, PendragonRoll};
+pub use runequest::{RqResult, RqRoll};
+pub use savage_worlds::SavageRoll;
+pub use shadowrun::{ShadowrunResult, ShadowrunRoll};
+pub use spire::{SpireResult, SpireRoll};
+pub use star_wars_ffg::{SwFfgPool, SwFfgResult};
+pub use symbaroum::SymbaroumRoll;
+pub use torchbearer::{TbResult, TbRoll};
+pub use troika::{TroikaResult, TroikaRoll};
+pub use twilight2000::{T2kResult, T2kRoll};
+pub use ultraviolet_grasslands::{UvgResult, UvgRoll};
+pub use vaesen::{VaesenResult, VaesenRoll};
+pub use vampire::VampireRoll;
+pub use white_hack::WhiteHackUsageDie;
+pub use wrath_glory::{WgResult, WrathGlory};
+pub use year_zero::{YzeResult, YzeRoll};
+pub use zweihander::{ZweiResult, ZweihanderRoll};
+
+/// Tries to parse input like [`Roll::parse_roll()`] does, but without options the game doesn't use.
+///
+/// * Exploding dice, keeping or dropping dice and Fate dice return [`RollError::ParsingError`].
+fn parse_plain_roll(input: &str) -> Result<Roll, RollError> {
+    plain_roll(Roll::parse_roll(input)?)
+}
+
+/// Returns [`RollError::ParsingError`] if the roll explodes, keeps only some dice or uses Fate dice.
+fn plain_roll(roll: Roll) -> Result<Roll, RollError> {
+    if roll.exploding || roll.keep_mode.is_some() || roll.die_kind != DieKind::Standard {
+        return Err(RollError::ParsingError);
+    }
+
+    Ok(roll)
+}
+
+/// Tries to parse input as a pool of dice with the given type of die and no modifier (e.g. `8d6`).
+///
+/// * Options like exploding dice aren't allowed, see [parse_plain_roll].
+fn parse_pool(input: &str, number_of_sides: u16) -> Result<Roll, RollError> {
+    let pool = parse_plain_roll(input)?;
+
+    if pool.number_of_sides != number_of_sides {
+        return Err(RollError::DieTypeInvalid);
+    } else if pool.modifier != 0 {
+        return Err(RollError::ParsingError);
+    }
+
+    Ok(pool)
+}
+
+/// Tries to parse input as a single die marked by the given suffix (e.g. `d12u`).
+///
+/// * Whitespaces are ignored.
+/// * The die may be written with or without `1`.
+/// * The die has to be one of the given types and can't have a modifier or other options.
+fn parse_suffixed_die(input: &str, suffix: &str, die_types: &[u16]) -> Result<Roll, RollError> {
+    let whitespaceless = input.replace(' ', "");
+
+    let notation = whitespaceless
+        .strip_suffix(suffix)
+        .ok_or(RollError::ParsingError)?;
+    let notation = match notation.strip_prefix('d') {
+        Some(sides) => format!("1d{sides}"),
+        None => notation.to_string(),
+    };
+
+    let die = plain_roll(Roll::parse_modified_roll(&notation)?)?;
+    if die.number_of_dice != 1 || die.modifier != 0 {
+        return Err(RollError::ParsingError);
+    }
+    die.check_roll_validity_with_die_types(1, die_types)?;
+
+    Ok(die)
+}
+
+/// Returns the die following the given one in a chain of die types, or `None` at the end of the chain.
+fn next_die_in_chain(chain: &[u16], die: &Roll) -> Option<Roll> {
+    let position = chain
+        .iter()
+        .position(|&sides| sides == die.number_of_sides)?;
+
+    chain.get(position + 1).map(|&sides| Roll::new(sides, 1, 0))
+}
+
+/// Tries to parse input as a roll-under check against a target number (e.g. `1d20<=15`).
+///
+/// The dice are parsed like [parse_pool].
+fn parse_roll_under(input: &str, number_of_sides: u16) -> Result<(Roll, u16), RollError> {
+    let (pool, target) = input.split_once("<=").ok_or(RollError::ParsingError)?;
+
+    let pool = parse_pool(pool, number_of_sides)?;
+    let target = target.trim().parse().map_err(|_| RollError::ParsingError)?;
+
+    Ok((pool, target))
+}
@@ -0,0 +1,178 @@
+use crate::{Roll, RollError};
+
+/// The highest entry of the Mothership panic table.
+const PANIC_TABLE_ENTRIES: u16 = 20;
+
+/// A Mothership percentile roll.
+#[derive(Debug, PartialEq)]
+pub struct MothershipRoll {
+    /// The parsed roll, which is always `1d100`.
+    pub roll: Roll,
+}
+impl MothershipRoll {
+    /// Checks whether a rolled result is a critical, which happens on doubles (11, 22, ..., 99).
+    pub fn is_crit_success(result: u16) -> bool {
+        (11..=99).contains(&result) && result / 10 == result % 10
+    }
+}
+
+/// A Mothership stress die, which is doubled into the panic roll (e.g. `1d10` ~> `2d10`).
+#[derive(Debug, PartialEq)]
+pub struct MotherStress {
+    /// The parsed stress die, which is always `1d10`.
+    pub stress_die: Roll,
+}
+impl MotherStress {
+    /// The panic roll, which uses twice the dice of the stress die.
+    pub fn panic_dice(&self) -> Roll {
+        Roll::new(
+            self.stress_die.number_of_sides,
+            self.stress_die.number_of_dice.saturating_mul(2),
+            0,
+        )
+    }
+
+    /// Looks up the entry of the panic table for the results of the panic dice and the current stress.
+    ///
+    /// Totals beyond the table are clamped to its last entry.
+    pub fn panic_entry(results: &[u16], stress: u8) -> u16 {
+        let total = results.iter().fold(u16::from(stress), |total, &result| {
+            total.saturating_add(result)
+        });
+
+        total.min(PANIC_TABLE_ENTRIES)
+    }
+}
+#[cfg(feature = "rand")]
+impl MotherStress {
+    /// Rolls the panic dice, adds the stress and returns the entry of the panic table.
+    pub fn panic_roll<R: rand::Rng + ?Sized>(&self, stress: u8, rng: &mut R) -> u16 {
+        let panic_dice = self.panic_dice();
+        let results =
+            crate::rolling::roll_dice(rng, panic_dice.number_of_dice, panic_dice.number_of_sides);
+
+        MotherStress::panic_entry(&results, stress)
+    }
+}
+
+impl Roll {
+    /// **Tries to parse input as a Mothership percentile roll (e.g. `1d100`).**
+    ///
+    /// * Whitespaces are ignored.
+    /// * The roll has to be exactly `1d100`.
+    ///
+    /// # Examples
+    /// ```
+    /// use die_parser::Roll;
+    /// use die_parser::systems::MothershipRoll;
+    ///
+    /// let mothership_roll = Roll::parse_roll_from_mothership_notation("1d100").unwrap();
+    /// assert_eq!(mothership_roll.roll, Roll::new(100, 1, 0));
+    /// assert!(MothershipRoll::is_crit_success(44));
+    /// ```
+    pub fn parse_roll_from_mothership_notation(input: &str) -> Result<MothershipRoll, RollError> {
+        let roll = super::parse_pool(input, 100)?;
+
+        if roll.number_of_dice != 1 {
+            return Err(RollError::ParsingError);
+        }
+
+        Ok(MothershipRoll { roll })
+    }
+
+    /// **Tries to parse input as a Mothership stress die (e.g. `1d10`).**
+    ///
+    /// * Whitespaces are ignored.
+    /// * The roll has to be exactly `1d10`, which becomes `2d10` for the panic roll.
+    ///
+    /// # Examples
+    /// ```
+    /// use die_parser::Roll;
+    /// use die_parser::systems::MotherStress;
+    ///
+    /// let stress = Roll::parse_roll_from_mothership_stress("1d10").unwrap();
+    /// assert_eq!(stress.panic_dice(), Roll::new(10, 2, 0));
+    /// assert_eq!(MotherStress::panic_entry(&[3, 4], 5), 12);
+    /// ```
+    pub fn parse_roll_from_mothership_stress(input: &str) -> Result<MotherStress, RollError> {
+        let stress_die = super::parse_pool(input, 10)?;
+
+        if stress_die.number_of_dice != 1 {
+            return Err(RollError::ParsingError);
+        }
+
+        Ok(MotherStress { stress_die })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_roll_from_mothership_notation() {
+        let tests = [
+            ("1d100", Ok(Roll::new(100, 1, 0))),
+            ("2d100", Err(RollError::ParsingError)),
+            ("1d100+5", Err(RollError::ParsingError)),
+            ("1d100!", Err(RollError::ParsingError)),
+            ("1d20", Err(RollError::DieTypeInvalid)),
+        ];
+
+        for (input, expected_output) in tests {
+            let output = Roll::parse_roll_from_mothership_notation(input).map(|roll| roll.roll);
+            assert_eq!(output, expected_output);
+        }
+    }
+
+    #[test]
+    fn test_parse_roll_from_mothership_stress() {
+        let tests = [
+            ("1d10", Ok(Roll::new(10, 2, 0))),
+            ("2d10", Err(RollError::ParsingError)),
+            ("1d10+1", Err(RollError::ParsingError)),
+            ("1d20", Err(RollError::DieTypeInvalid)),
+        ];
+
+        for (input, expected_output) in tests {
+            let output =
+                Roll::parse_roll_from_mothership_stress(input).map(|stress| stress.panic_dice());
+            assert_eq!(output, expected_output);
+        }
+    }
+
+    #[test]
+    fn test_panic_entry() {
+        let tests = [
+            ((vec![1, 1], 0), 2),
+            ((vec![3, 4], 5), 12),
+            ((vec![10, 10], 0), 20),
+            ((vec![9, 8], 10), 20),
+        ];
+
+        for ((results, stress), expected_output) in tests {
+            assert_eq!(MotherStress::panic_entry(&results, stress), expected_output);
+        }
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn test_panic_roll() {
+        let mut rng = rand::thread_rng();
+        let stress = Roll::parse_roll_from_mothership_stress("1d10").unwrap();
+
+        for _ in 0..100 {
+            assert!((5..=20).contains(&stress.panic_roll(3, &mut rng)));
+        }
+    }
+
+    #[test]
+    fn test_is_crit_success() {
+        for result in [11, 22, 33, 44, 55, 66, 77, 88, 99] {
+            assert!(MothershipRoll::is_crit_success(result));
+        }
+        for result in [0, 1, 10, 12, 50, 100, 110] {
+            assert!(!MothershipRoll::is_crit_success(result));
+        }
+    }
+}
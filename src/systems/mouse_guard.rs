@@ -0,0 +1,96 @@
+use crate::{Roll, RollError};
+
+/// A Mouse Guard dice pool, in which every 4 to 6 is a success.
+#[derive(Debug, PartialEq)]
+pub struct MgRoll {
+    /// The pool of d6s to be rolled.
+    pub pool: Roll,
+}
+#[cfg(feature = "rand")]
+impl MgRoll {
+    /// Rolls the pool and counts successes and twists.
+    pub fn roll<R: rand::Rng + ?Sized>(&self, rng: &mut R) -> MgResult {
+        let results = crate::rolling::roll_dice(rng, self.pool.number_of_dice, 6);
+
+        MgResult::from_results(&results)
+    }
+}
+
+/// The outcome of a [MgRoll].
+#[derive(Debug, PartialEq)]
+pub struct MgResult {
+    /// How many dice showed 4 to 6.
+    pub successes: u16,
+    /// How many dice showed a 1, each of which may cause a condition.
+    pub twists: u16,
+}
+impl MgResult {
+    /// Evaluates the results of the single dice of a pool.
+    pub fn from_results(results: &[u16]) -> Self {
+        Self {
+            successes: results.iter().filter(|&&result| result >= 4).count() as u16,
+            twists: results.iter().filter(|&&result| result == 1).count() as u16,
+        }
+    }
+
+    /// Checks whether the successes meet the obstacle of a test.
+    pub fn ob_met(&self, ob: u8) -> bool {
+        self.successes >= u16::from(ob)
+    }
+}
+
+impl Roll {
+    /// **Tries to parse input as a Mouse Guard dice pool (e.g. `4d6`).**
+    ///
+    /// * Whitespaces are ignored.
+    /// * Only d6s without a modifier are allowed.
+    /// * Enforces a limit of 100 dice per pool.
+    ///
+    /// # Examples
+    /// ```
+    /// use die_parser::Roll;
+    ///
+    /// let mg_roll = Roll::parse_roll_from_mouseguard("4d6").unwrap();
+    /// assert_eq!(mg_roll.pool, Roll::new(6, 4, 0));
+    /// ```
+    pub fn parse_roll_from_mouseguard(input: &str) -> Result<MgRoll, RollError> {
+        let pool = super::parse_pool(input, 6)?;
+
+        Ok(MgRoll { pool })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_roll_from_mouseguard() {
+        let tests = [
+            ("4d6", Ok(Roll::new(6, 4, 0))),
+            ("4d6+1", Err(RollError::ParsingError)),
+            ("4d8", Err(RollError::DieTypeInvalid)),
+            ("0d6", Err(RollError::NoDiceToRoll)),
+        ];
+
+        for (input, expected_output) in tests {
+            let output = Roll::parse_roll_from_mouseguard(input).map(|roll| roll.pool);
+            assert_eq!(output, expected_output);
+        }
+    }
+
+    #[test]
+    fn test_mg_result() {
+        let tests = [
+            (vec![4, 6, 1, 3], (2, 1), true),
+            (vec![1, 1, 5], (1, 2), false),
+            (vec![], (0, 0), false),
+        ];
+
+        for (results, (successes, twists), expected_ob_met) in tests {
+            let output = MgResult::from_results(&results);
+            assert_eq!(output, MgResult { successes, twists });
+            assert_eq!(output.ob_met(2), expected_ob_met);
+        }
+    }
+}
@@ -0,0 +1,101 @@
+use crate::{Roll, RollError};
+
+/// A Numenera roll, which always rolls a d20.
+#[derive(Debug, PartialEq)]
+pub struct NumenRoll {
+    /// The parsed roll, which is always `1d20`.
+    pub roll: Roll,
+    /// The levels of effort applied, given as the modifier of the roll.
+    pub effort: Option<i32>,
+}
+impl NumenRoll {
+    /// Determines the special effect of a rolled result.
+    ///
+    /// | Result | Effect                         |
+    /// |--------|--------------------------------|
+    /// | 1      | [`NumenEffect::GmIntrusion`]   |
+    /// | 2-16   | [`NumenEffect::None`]          |
+    /// | 17-19  | [`NumenEffect::MinorEffect`]   |
+    /// | 20     | [`NumenEffect::MajorEffect`]   |
+    pub fn effect(result: u16) -> NumenEffect {
+        match result {
+            1 => NumenEffect::GmIntrusion,
+            17..=19 => NumenEffect::MinorEffect,
+            20 => NumenEffect::MajorEffect,
+            _ => NumenEffect::None,
+        }
+    }
+}
+
+/// The special effect of a [NumenRoll].
+#[derive(Debug, PartialEq)]
+pub enum NumenEffect {
+    /// A 1, the GM is offered an intrusion.
+    GmIntrusion,
+    /// No special effect.
+    None,
+    /// A 17 to 19.
+    MinorEffect,
+    /// A 20.
+    MajorEffect,
+}
+
+impl Roll {
+    /// **Tries to parse input as a Numenera roll (e.g. `1d20` or `1d20+2`).**
+    ///
+    /// * Whitespaces are ignored.
+    /// * The dice have to be exactly `1d20`.
+    /// * A modifier is read as the levels of effort applied.
+    ///
+    /// # Examples
+    /// ```
+    /// use die_parser::Roll;
+    /// use die_parser::systems::{NumenEffect, NumenRoll};
+    ///
+    /// let numen_roll = Roll::parse_roll_from_numenera("1d20+2").unwrap();
+    /// assert_eq!(numen_roll.effort, Some(2));
+    /// assert_eq!(NumenRoll::effect(1), NumenEffect::GmIntrusion);
+    /// ```
+    pub fn parse_roll_from_numenera(input: &str) -> Result<NumenRoll, RollError> {
+        // Numenera is the original Cypher System game.
+        let roll = Roll::parse_roll_from_cypher_notation(input)?;
+        let effort = (roll.modifier != 0).then_some(roll.modifier);
+
+        Ok(NumenRoll { roll, effort })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_roll_from_numenera() {
+        let tests = [
+            ("1d20", Ok(None)),
+            ("1d20 + 2", Ok(Some(2))),
+            ("2d20", Err(RollError::ParsingError)),
+            ("1d12", Err(RollError::DieTypeInvalid)),
+        ];
+
+        for (input, expected_output) in tests {
+            let output = Roll::parse_roll_from_numenera(input).map(|roll| roll.effort);
+            assert_eq!(output, expected_output);
+        }
+    }
+
+    #[test]
+    fn test_effect() {
+        let tests = [
+            (1, NumenEffect::GmIntrusion),
+            (2, NumenEffect::None),
+            (16, NumenEffect::None),
+            (17, NumenEffect::MinorEffect),
+            (20, NumenEffect::MajorEffect),
+        ];
+
+        for (result, expected_output) in tests {
+            assert_eq!(NumenRoll::effect(result), expected_output);
+        }
+    }
+}
@@ -0,0 +1,126 @@
+use crate::{Roll, RollError};
+
+/// The result a die has to meet or exceed to be rolled again, if not configured otherwise.
+const DEFAULT_AGAIN: u16 = 10;
+
+/// The highest amount of times a single die is rolled again.
+#[cfg(feature = "rand")]
+const MAX_REROLLS: u16 = 100;
+
+/// A New World of Darkness dice pool.
+#[derive(Debug, PartialEq)]
+pub struct NwodRoll {
+    /// The pool of d10s to be rolled.
+    pub pool: Roll,
+    /// The result a die has to meet or exceed to be rolled again (e.g. `10` for "10-again").
+    pub again: u16,
+}
+impl NwodRoll {
+    /// Sets the result a die has to meet or exceed to be rolled again (e.g. `8` for "8-again").
+    pub fn with_again(self, again: u16) -> Self {
+        Self { again, ..self }
+    }
+
+    /// Counts the successes of the given results, including any dice that were rolled again.
+    ///
+    /// Results of 8 or higher are successes, while every 1 cancels a success.
+    pub fn count_successes(results: &[u16]) -> u16 {
+        let successes = results.iter().filter(|&&result| result >= 8).count();
+        let ones = results.iter().filter(|&&result| result == 1).count();
+
+        successes.saturating_sub(ones) as u16
+    }
+}
+#[cfg(feature = "rand")]
+impl NwodRoll {
+    /// Rolls the pool, rolling dice again that meet the "again" threshold, and counts the successes.
+    pub fn roll<R: rand::Rng + ?Sized>(&self, rng: &mut R) -> u16 {
+        let mut results = Vec::new();
+
+        for _ in 0..self.pool.number_of_dice {
+            let mut result = crate::rolling::roll_die(rng, 10);
+            results.push(result);
+
+            // Keep rolling as long as the die meets the threshold.
+            let mut rerolls = 0;
+            while result >= self.again && rerolls < MAX_REROLLS {
+                result = crate::rolling::roll_die(rng, 10);
+                results.push(result);
+                rerolls += 1;
+            }
+        }
+
+        NwodRoll::count_successes(&results)
+    }
+}
+
+impl Roll {
+    /// **Tries to parse input as a New World of Darkness dice pool (e.g. `5d10`).**
+    ///
+    /// * Only d10s without a modifier are allowed.
+    /// * Dice showing a 10 are rolled again, see [`NwodRoll::with_again()`] to change that.
+    /// * Enforces a limit of 100 dice per pool.
+    ///
+    /// # Examples
+    /// ```
+    /// use die_parser::Roll;
+    ///
+    /// let nwod_roll = Roll::parse_roll_from_nwod("5d10").unwrap().with_again(8);
+    /// assert_eq!(nwod_roll.pool, Roll::new(10, 5, 0));
+    /// assert_eq!(nwod_roll.again, 8);
+    /// ```
+    pub fn parse_roll_from_nwod(input: &str) -> Result<NwodRoll, RollError> {
+        let pool = super::parse_pool(input, 10)?;
+
+        Ok(NwodRoll {
+            pool,
+            again: DEFAULT_AGAIN,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_roll_from_nwod() {
+        let tests = [
+            ("5d10", Ok(Roll::new(10, 5, 0))),
+            ("5d6", Err(RollError::DieTypeInvalid)),
+            ("5d10+1", Err(RollError::ParsingError)),
+            ("5d10!", Err(RollError::ParsingError)),
+        ];
+
+        for (input, expected_output) in tests {
+            let output = Roll::parse_roll_from_nwod(input).map(|roll| roll.pool);
+            assert_eq!(output, expected_output);
+        }
+    }
+
+    #[test]
+    fn test_count_successes() {
+        let tests = [
+            (vec![8, 9, 10, 3], 3),
+            (vec![8, 1, 10, 7], 1),
+            (vec![1, 1, 9], 0),
+            (vec![], 0),
+        ];
+
+        for (input, expected_output) in tests {
+            assert_eq!(NwodRoll::count_successes(&input), expected_output);
+        }
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn test_nwod_roll_again() {
+        let mut rng = rand::thread_rng();
+
+        // With "2-again", a die is rolled again until it shows a 1 or the rerolls are capped.
+        let nwod_roll = Roll::parse_roll_from_nwod("1d10").unwrap().with_again(2);
+        for _ in 0..100 {
+            assert!(nwod_roll.roll(&mut rng) <= MAX_REROLLS + 1);
+        }
+    }
+}
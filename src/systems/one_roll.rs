@@ -0,0 +1,128 @@
+use crate::{Roll, RollError};
+
+/// A One Roll Engine dice pool, whose results are grouped into sets.
+#[derive(Debug, PartialEq)]
+pub struct OreRoll {
+    /// The pool of dice to be rolled.
+    pub pool: Roll,
+}
+impl OreRoll {
+    /// Groups the results of the single dice of a pool into sets of matching faces.
+    ///
+    /// Dice that don't match any other die don't form a set.
+    /// Sets are ordered by height, highest first.
+    pub fn sets_from_results(results: &[u16]) -> Vec<OreSet> {
+        let mut sets = Vec::new();
+
+        let mut heights = results.to_vec();
+        heights.sort_unstable_by(|a, b| b.cmp(a));
+        heights.dedup();
+
+        for height in heights {
+            let width = results.iter().filter(|&&result| result == height).count();
+            if width > 1 {
+                sets.push(OreSet {
+                    width: width as u8,
+                    height: height as u8,
+                });
+            }
+        }
+
+        sets
+    }
+}
+#[cfg(feature = "rand")]
+impl OreRoll {
+    /// Rolls the pool and groups the results into sets.
+    pub fn roll<R: rand::Rng + ?Sized>(&self, rng: &mut R) -> Vec<OreSet> {
+        let results =
+            crate::rolling::roll_dice(rng, self.pool.number_of_dice, self.pool.number_of_sides);
+
+        OreRoll::sets_from_results(&results)
+    }
+}
+
+/// A set of dice showing the same face in an [OreRoll].
+#[derive(Debug, PartialEq)]
+pub struct OreSet {
+    /// How many dice show the face.
+    pub width: u8,
+    /// The face the dice show.
+    pub height: u8,
+}
+
+impl Roll {
+    /// **Tries to parse input as a One Roll Engine dice pool (e.g. `6d10`).**
+    ///
+    /// * Whitespaces are ignored.
+    /// * A modifier isn't allowed.
+    /// * Checks for validity of roll the same way [`Roll::parse_roll()`] does.
+    ///
+    /// # Examples
+    /// ```
+    /// use die_parser::Roll;
+    /// use die_parser::systems::{OreRoll, OreSet};
+    ///
+    /// let ore_roll = Roll::parse_roll_from_oneroll_notation("6d10").unwrap();
+    /// assert_eq!(ore_roll.pool, Roll::new(10, 6, 0));
+    ///
+    /// let sets = OreRoll::sets_from_results(&[3, 7, 3, 3, 1, 7]);
+    /// assert_eq!(sets[0], OreSet { width: 2, height: 7 });
+    /// ```
+    pub fn parse_roll_from_oneroll_notation(input: &str) -> Result<OreRoll, RollError> {
+        let pool = super::parse_plain_roll(input)?;
+
+        if pool.modifier != 0 {
+            return Err(RollError::ParsingError);
+        }
+
+        Ok(OreRoll { pool })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_roll_from_oneroll_notation() {
+        let tests = [
+            ("6d10", Ok(Roll::new(10, 6, 0))),
+            ("4d6", Ok(Roll::new(6, 4, 0))),
+            ("6d10+1", Err(RollError::ParsingError)),
+            ("6d10kh3", Err(RollError::ParsingError)),
+            ("6d10!", Err(RollError::ParsingError)),
+            ("6d9", Err(RollError::DieTypeInvalid)),
+        ];
+
+        for (input, expected_output) in tests {
+            let output = Roll::parse_roll_from_oneroll_notation(input).map(|roll| roll.pool);
+            assert_eq!(output, expected_output);
+        }
+    }
+
+    #[test]
+    fn test_sets_from_results() {
+        let tests = [
+            (
+                vec![3, 7, 3, 3, 1, 7],
+                vec![
+                    OreSet {
+                        width: 2,
+                        height: 7,
+                    },
+                    OreSet {
+                        width: 3,
+                        height: 3,
+                    },
+                ],
+            ),
+            (vec![1, 2, 3], vec![]),
+            (vec![], vec![]),
+        ];
+
+        for (results, expected_output) in tests {
+            assert_eq!(OreRoll::sets_from_results(&results), expected_output);
+        }
+    }
+}
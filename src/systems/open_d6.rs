@@ -0,0 +1,108 @@
+use crate::{Roll, RollError};
+
+/// An Open D6 roll, made of d6s and pips.
+#[derive(Debug, PartialEq)]
+pub struct OpenD6Roll {
+    /// The d6s to be rolled, with the pips as modifier.
+    pub roll: Roll,
+    /// How many pips are added to the roll (between `0` and `2`).
+    pub pips: u8,
+}
+
+impl Roll {
+    /// **Tries to parse input as OpenD6 MiniSix roll notation (e.g. `3D+2`).**
+    ///
+    /// * Whitespaces are ignored and `D` may be written in either case.
+    /// * Only d6s are used, so the type of die may be omitted.
+    /// * The pips are stored as modifier and have to be `0`, `1` or `2`.
+    /// * Enforces a limit of 100 dice per roll.
+    ///
+    /// # Examples
+    /// ```
+    /// use die_parser::{Roll, RollError};
+    ///
+    /// let roll = Roll::parse_roll_from_opend6_notation("3D+2");
+    /// assert_eq!(roll, Ok(Roll::new(6, 3, 2)));
+    ///
+    /// let invalid_roll = Roll::parse_roll_from_opend6_notation("3D-1");
+    /// assert_eq!(invalid_roll, Err(RollError::ModifierExceedsLimit));
+    /// ```
+    pub fn parse_roll_from_opend6_notation(input: &str) -> Result<Roll, RollError> {
+        // Open D6 shares its notation with Traveller, but limits the modifier.
+        let roll = Roll::parse_roll_from_traveller(input)?;
+
+        if !(0..=2).contains(&roll.modifier) {
+            return Err(RollError::ModifierExceedsLimit);
+        }
+
+        Ok(roll)
+    }
+
+    /// **Tries to parse input as Open D6 roll notation (e.g. `3D+2`).**
+    ///
+    /// * Whitespaces are ignored and `D` may be written in either case.
+    /// * Only d6s are used, so the type of die may be omitted.
+    /// * Up to two pips may be added, each adding `1` to the result.
+    /// * Enforces a limit of 100 dice per roll.
+    ///
+    /// # Examples
+    /// ```
+    /// use die_parser::{Roll, RollError};
+    ///
+    /// let open_d6_roll = Roll::parse_roll_from_open_d6("3D+2").unwrap();
+    /// assert_eq!(open_d6_roll.roll, Roll::new(6, 3, 2));
+    /// assert_eq!(open_d6_roll.pips, 2);
+    ///
+    /// let invalid_roll = Roll::parse_roll_from_open_d6("3D+3");
+    /// assert_eq!(invalid_roll, Err(RollError::ModifierExceedsLimit));
+    /// ```
+    pub fn parse_roll_from_open_d6(input: &str) -> Result<OpenD6Roll, RollError> {
+        let roll = Roll::parse_roll_from_opend6_notation(input)?;
+
+        Ok(OpenD6Roll {
+            roll,
+            pips: roll.modifier as u8,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_roll_from_opend6_notation() {
+        let tests = [
+            ("3D", Ok(Roll::new(6, 3, 0))),
+            ("3d+1", Ok(Roll::new(6, 3, 1))),
+            ("4D + 2", Ok(Roll::new(6, 4, 2))),
+            ("3D+3", Err(RollError::ModifierExceedsLimit)),
+            ("3D-2", Err(RollError::ModifierExceedsLimit)),
+            ("3D10+1", Err(RollError::DieTypeInvalid)),
+        ];
+
+        for (input, expected_output) in tests {
+            let output = Roll::parse_roll_from_opend6_notation(input);
+            assert_eq!(output, expected_output);
+        }
+    }
+
+    #[test]
+    fn test_parse_roll_from_open_d6() {
+        let tests = [
+            ("3D", Ok((Roll::new(6, 3, 0), 0))),
+            ("3D+1", Ok((Roll::new(6, 3, 1), 1))),
+            ("5D + 2", Ok((Roll::new(6, 5, 2), 2))),
+            ("2D6+1", Ok((Roll::new(6, 2, 1), 1))),
+            ("3D+3", Err(RollError::ModifierExceedsLimit)),
+            ("3D-1", Err(RollError::ModifierExceedsLimit)),
+            ("3D8", Err(RollError::DieTypeInvalid)),
+        ];
+
+        for (input, expected_output) in tests {
+            let output = Roll::parse_roll_from_open_d6(input)
+                .map(|open_d6_roll| (open_d6_roll.roll, open_d6_roll.pips));
+            assert_eq!(output, expected_output);
+        }
+    }
+}
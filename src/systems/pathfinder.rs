@@ -0,0 +1,113 @@
+use crate::{Roll, RollError};
+
+impl Roll {
+    /// **Tries to parse input as Pathfinder 2e roll notation (e.g. `d20+5` or `1d4+1d4+2`).**
+    ///
+    /// * Whitespaces are ignored.
+    /// * A missing amount of dice is read as a single die (e.g. `d20`).
+    /// * Dice of the same type may be combined (e.g. `1d4+1d4` is read as `2d4`).
+    ///     * Combining different types of dice (e.g. `1d6+1d4`) results in a [`RollError::ParsingError`].
+    /// * Checks for validity of roll the same way [`Roll::parse_roll()`] does.
+    ///
+    /// # Examples
+    /// ```
+    /// use die_parser::Roll;
+    ///
+    /// let roll = Roll::parse_roll_from_pathfinder_notation("d20+5");
+    /// assert_eq!(roll, Ok(Roll::new(20, 1, 5)));
+    ///
+    /// let roll = Roll::parse_roll_from_pathfinder_notation("1d4 + 1d4 + 2");
+    /// assert_eq!(roll, Ok(Roll::new(4, 2, 2)));
+    /// ```
+    pub fn parse_roll_from_pathfinder_notation(input: &str) -> Result<Roll, RollError> {
+        // Remove whitespaces.
+        let whitespaceless = input.replace(' ', "");
+
+        let mut dice: Option<(u16, u16)> = None;
+        let mut modifier: i32 = 0;
+
+        for (is_negative, term) in split_terms(&whitespaceless) {
+            match term.split_once('d') {
+                // Dice can only be added.
+                Some((count, sides)) if !is_negative => {
+                    let count = match count {
+                        "" => 1,
+                        count => count.parse().map_err(|_| RollError::ParsingError)?,
+                    };
+                    let sides = sides.parse().map_err(|_| RollError::ParsingError)?;
+
+                    dice = match dice {
+                        None => Some((count, sides)),
+                        Some((total, previous_sides)) if previous_sides == sides => {
+                            Some((total.saturating_add(count), sides))
+                        }
+                        Some(_) => return Err(RollError::ParsingError),
+                    };
+                }
+                Some(_) => return Err(RollError::ParsingError),
+                None => {
+                    let value: i32 = term
+                        .parse::<u16>()
+                        .map_err(|_| RollError::ParsingError)?
+                        .into();
+                    let value = if is_negative { -value } else { value };
+
+                    modifier = modifier
+                        .checked_add(value)
+                        .ok_or(RollError::ModifierOverflow)?;
+                }
+            }
+        }
+
+        let (number_of_dice, number_of_sides) = dice.ok_or(RollError::ParsingError)?;
+        let result = Roll::new(number_of_sides, number_of_dice, modifier);
+        result.check_roll_validity(100)?;
+        Ok(result)
+    }
+}
+
+/// Splits the input into terms separated by `+` or `-`, noting whether each term is subtracted.
+fn split_terms(input: &str) -> Vec<(bool, &str)> {
+    let mut terms = Vec::new();
+    let mut is_negative = false;
+    let mut start = 0;
+
+    for (index, c) in input.char_indices() {
+        if c == '+' || c == '-' {
+            terms.push((is_negative, &input[start..index]));
+            is_negative = c == '-';
+            start = index + 1;
+        }
+    }
+    terms.push((is_negative, &input[start..]));
+
+    terms
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_roll_from_pathfinder_notation() {
+        let tests = [
+            ("d20+5", Ok(Roll::new(20, 1, 5))),
+            ("d20 - 1", Ok(Roll::new(20, 1, -1))),
+            ("2d6", Ok(Roll::new(6, 2, 0))),
+            ("1d4+1d4", Ok(Roll::new(4, 2, 0))),
+            ("1d8+4+1d8-1", Ok(Roll::new(8, 2, 3))),
+            ("1d6+1d4", Err(RollError::ParsingError)),
+            ("1d6-1d6", Err(RollError::ParsingError)),
+            ("d20++5", Err(RollError::ParsingError)),
+            ("5", Err(RollError::ParsingError)),
+            ("d7", Err(RollError::DieTypeInvalid)),
+        ];
+
+        for (input, expected_output) in tests {
+            assert_eq!(
+                Roll::parse_roll_from_pathfinder_notation(input),
+                expected_output
+            );
+        }
+    }
+}
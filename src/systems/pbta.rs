@@ -0,0 +1,101 @@
+use crate::{Roll, RollError};
+
+/// A Powered by the Apocalypse move, which always rolls 2d6 plus a stat.
+#[derive(Debug, PartialEq)]
+pub struct PbtaRoll {
+    /// The parsed roll, with the stat as modifier.
+    pub roll: Roll,
+}
+impl PbtaRoll {
+    /// Determines the outcome band of a rolled result.
+    ///
+    /// | Result | Outcome                   |
+    /// |--------|---------------------------|
+    /// | 6-     | [`PbtaOutcome::Miss`]     |
+    /// | 7-9    | [`PbtaOutcome::Partial`]  |
+    /// | 10+    | [`PbtaOutcome::FullHit`]  |
+    pub fn outcome(result: i32) -> PbtaOutcome {
+        match result {
+            i32::MIN..=6 => PbtaOutcome::Miss,
+            7..=9 => PbtaOutcome::Partial,
+            _ => PbtaOutcome::FullHit,
+        }
+    }
+}
+
+/// The outcome band of a [PbtaRoll].
+#[derive(Debug, PartialEq)]
+pub enum PbtaOutcome {
+    /// A result of 6 or less.
+    Miss,
+    /// A result of 7 to 9, a success with a cost.
+    Partial,
+    /// A result of 10 or more.
+    FullHit,
+}
+
+impl Roll {
+    /// **Tries to parse input as a Powered by the Apocalypse move (e.g. `2d6+2`).**
+    ///
+    /// * Whitespaces are ignored.
+    /// * The dice have to be exactly `2d6`.
+    ///
+    /// # Examples
+    /// ```
+    /// use die_parser::Roll;
+    /// use die_parser::systems::{PbtaOutcome, PbtaRoll};
+    ///
+    /// let pbta_roll = Roll::parse_roll_from_pbta_notation("2d6+2").unwrap();
+    /// assert_eq!(pbta_roll.roll, Roll::new(6, 2, 2));
+    /// assert_eq!(PbtaRoll::outcome(8), PbtaOutcome::Partial);
+    /// ```
+    pub fn parse_roll_from_pbta_notation(input: &str) -> Result<PbtaRoll, RollError> {
+        let roll = super::parse_plain_roll(input)?;
+
+        if roll.number_of_sides != 6 {
+            return Err(RollError::DieTypeInvalid);
+        } else if roll.number_of_dice != 2 {
+            return Err(RollError::ParsingError);
+        }
+
+        Ok(PbtaRoll { roll })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_roll_from_pbta_notation() {
+        let tests = [
+            ("2d6+2", Ok(Roll::new(6, 2, 2))),
+            ("2d6 - 1", Ok(Roll::new(6, 2, -1))),
+            ("3d6+2", Err(RollError::ParsingError)),
+            ("2d6!+1", Err(RollError::ParsingError)),
+            ("2d6kh1", Err(RollError::ParsingError)),
+            ("2d8+2", Err(RollError::DieTypeInvalid)),
+        ];
+
+        for (input, expected_output) in tests {
+            let output = Roll::parse_roll_from_pbta_notation(input).map(|roll| roll.roll);
+            assert_eq!(output, expected_output);
+        }
+    }
+
+    #[test]
+    fn test_outcome() {
+        let tests = [
+            (-1, PbtaOutcome::Miss),
+            (6, PbtaOutcome::Miss),
+            (7, PbtaOutcome::Partial),
+            (9, PbtaOutcome::Partial),
+            (10, PbtaOutcome::FullHit),
+            (15, PbtaOutcome::FullHit),
+        ];
+
+        for (result, expected_output) in tests {
+            assert_eq!(PbtaRoll::outcome(result), expected_output);
+        }
+    }
+}
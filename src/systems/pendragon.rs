@@ -0,0 +1,103 @@
+use crate::{Roll, RollError};
+
+/// A Pendragon skill roll, rolling a d20 under the skill.
+#[derive(Debug, PartialEq)]
+pub struct PendragonRoll {
+    /// The parsed roll, which is always `1d20`.
+    pub roll: Roll,
+}
+impl PendragonRoll {
+    /// Determines the outcome of a rolled result against a skill.
+    ///
+    /// | Result                            | Outcome                    |
+    /// |-----------------------------------|----------------------------|
+    /// | equal to the skill or ≤ skill / 5 | [`PenResult::CritSuccess`] |
+    /// | 20                                | [`PenResult::Fumble`]      |
+    /// | ≤ skill                           | [`PenResult::Success`]     |
+    /// | > skill                           | [`PenResult::Failure`]     |
+    pub fn result(roll: u16, skill: u16) -> PenResult {
+        if roll == skill || roll <= skill / 5 {
+            PenResult::CritSuccess
+        } else if roll == 20 {
+            PenResult::Fumble
+        } else if roll <= skill {
+            PenResult::Success
+        } else {
+            PenResult::Failure
+        }
+    }
+}
+
+/// The outcome of a [PendragonRoll].
+#[derive(Debug, PartialEq)]
+pub enum PenResult {
+    /// A roll equal to the skill or at most a fifth of it.
+    CritSuccess,
+    /// A roll below the skill.
+    Success,
+    /// A roll above the skill.
+    Failure,
+    /// A roll of 20.
+    Fumble,
+}
+
+impl Roll {
+    /// **Tries to parse input as a Pendragon skill roll (e.g. `1d20`).**
+    ///
+    /// * Whitespaces are ignored.
+    /// * The roll has to be exactly `1d20`.
+    ///
+    /// # Examples
+    /// ```
+    /// use die_parser::Roll;
+    /// use die_parser::systems::{PenResult, PendragonRoll};
+    ///
+    /// let pendragon_roll = Roll::parse_roll_from_pendragon("1d20").unwrap();
+    /// assert_eq!(pendragon_roll.roll, Roll::new(20, 1, 0));
+    /// assert_eq!(PendragonRoll::result(15, 15), PenResult::CritSuccess);
+    /// ```
+    pub fn parse_roll_from_pendragon(input: &str) -> Result<PendragonRoll, RollError> {
+        let roll = super::parse_pool(input, 20)?;
+
+        if roll.number_of_dice != 1 {
+            return Err(RollError::ParsingError);
+        }
+
+        Ok(PendragonRoll { roll })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_roll_from_pendragon() {
+        let tests = [
+            ("1d20", Ok(Roll::new(20, 1, 0))),
+            ("2d20", Err(RollError::ParsingError)),
+            ("1d20+1", Err(RollError::ParsingError)),
+            ("1d100", Err(RollError::DieTypeInvalid)),
+        ];
+
+        for (input, expected_output) in tests {
+            let output = Roll::parse_roll_from_pendragon(input).map(|roll| roll.roll);
+            assert_eq!(output, expected_output);
+        }
+    }
+
+    #[test]
+    fn test_result() {
+        let tests = [
+            (3, PenResult::CritSuccess),
+            (15, PenResult::CritSuccess),
+            (10, PenResult::Success),
+            (16, PenResult::Failure),
+            (20, PenResult::Fumble),
+        ];
+
+        for (roll, expected_output) in tests {
+            assert_eq!(PendragonRoll::result(roll, 15), expected_output);
+        }
+    }
+}
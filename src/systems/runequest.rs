@@ -0,0 +1,112 @@
+use crate::{Roll, RollError};
+
+/// A RuneQuest skill roll, rolling a d100 under the skill.
+#[derive(Debug, PartialEq)]
+pub struct RqRoll {
+    /// The parsed roll, which is always `1d100`.
+    pub roll: Roll,
+    /// The skill the roll has to be equal to or lower than.
+    pub skill: u16,
+}
+impl RqRoll {
+    /// Determines the outcome of a rolled result against a skill.
+    ///
+    /// | Result        | Outcome                 |
+    /// |---------------|-------------------------|
+    /// | ≤ skill / 5   | [`RqResult::Critical`]  |
+    /// | ≤ skill / 2   | [`RqResult::Special`]   |
+    /// | ≤ skill       | [`RqResult::Success`]   |
+    /// | 100           | [`RqResult::Fumble`]    |
+    /// | > skill       | [`RqResult::Failure`]   |
+    pub fn result(roll: u16, skill: u16) -> RqResult {
+        if roll == 100 {
+            RqResult::Fumble
+        } else if roll <= skill / 5 {
+            RqResult::Critical
+        } else if roll <= skill / 2 {
+            RqResult::Special
+        } else if roll <= skill {
+            RqResult::Success
+        } else {
+            RqResult::Failure
+        }
+    }
+}
+
+/// The outcome of a [RqRoll].
+#[derive(Debug, PartialEq)]
+pub enum RqResult {
+    /// A roll of at most a fifth of the skill.
+    Critical,
+    /// A roll of at most half the skill.
+    Special,
+    /// A roll at or below the skill.
+    Success,
+    /// A roll above the skill.
+    Failure,
+    /// A roll of 100.
+    Fumble,
+}
+
+impl Roll {
+    /// **Tries to parse input as a RuneQuest skill roll (e.g. `1d100<=60`).**
+    ///
+    /// * Whitespaces are ignored.
+    /// * The roll has to be exactly `1d100`.
+    ///
+    /// # Examples
+    /// ```
+    /// use die_parser::Roll;
+    /// use die_parser::systems::{RqResult, RqRoll};
+    ///
+    /// let rq_roll = Roll::parse_roll_from_runequest("1d100<=60").unwrap();
+    /// assert_eq!(rq_roll.skill, 60);
+    /// assert_eq!(RqRoll::result(25, rq_roll.skill), RqResult::Special);
+    /// ```
+    pub fn parse_roll_from_runequest(input: &str) -> Result<RqRoll, RollError> {
+        let (roll, skill) = super::parse_roll_under(input, 100)?;
+
+        if roll.number_of_dice != 1 {
+            return Err(RollError::ParsingError);
+        }
+
+        Ok(RqRoll { roll, skill })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_roll_from_runequest() {
+        let tests = [
+            ("1d100<=60", Ok(60)),
+            ("1d100 <= 35", Ok(35)),
+            ("1d100", Err(RollError::ParsingError)),
+            ("2d100<=60", Err(RollError::ParsingError)),
+            ("1d20<=60", Err(RollError::DieTypeInvalid)),
+        ];
+
+        for (input, expected_output) in tests {
+            let output = Roll::parse_roll_from_runequest(input).map(|roll| roll.skill);
+            assert_eq!(output, expected_output);
+        }
+    }
+
+    #[test]
+    fn test_result() {
+        let tests = [
+            (12, RqResult::Critical),
+            (13, RqResult::Special),
+            (30, RqResult::Special),
+            (60, RqResult::Success),
+            (61, RqResult::Failure),
+            (100, RqResult::Fumble),
+        ];
+
+        for (roll, expected_output) in tests {
+            assert_eq!(RqRoll::result(roll, 60), expected_output);
+        }
+    }
+}
@@ -0,0 +1,123 @@
+use nom::bytes::complete::tag;
+use nom::character::complete::char;
+use nom::combinator::opt;
+use nom::sequence::{preceded, separated_pair};
+use nom::IResult;
+
+use crate::{parse_modifier, parse_numbers, Roll, RollError};
+
+/// A Savage Worlds trait roll, made of a trait die and a wild die.
+#[derive(Debug, PartialEq)]
+pub struct SavageRoll {
+    /// The die of the trait being tested.
+    pub trait_die: Roll,
+    /// The wild die, which is always a d6.
+    pub wild_die: Roll,
+    /// A modifier to be added to the higher of both dice.
+    pub modifier: i32,
+}
+#[cfg(feature = "rand")]
+impl SavageRoll {
+    /// Rolls both dice, drops the lower one and adds the modifier.
+    pub fn roll<R: rand::Rng + ?Sized>(&self, rng: &mut R) -> i32 {
+        let trait_result = crate::rolling::roll_die(rng, self.trait_die.number_of_sides);
+        let wild_result = crate::rolling::roll_die(rng, self.wild_die.number_of_sides);
+
+        (trait_result.max(wild_result) as i32).saturating_add(self.modifier)
+    }
+}
+
+impl Roll {
+    /// **Tries to parse input as a Savage Worlds trait roll (e.g. `d8+d6+1`).**
+    ///
+    /// * Whitespaces are ignored.
+    /// * The trait die comes first and is followed by the wild die.
+    ///     * The wild die has to be a d6.
+    /// * Checks for validity of the trait die.[^1]
+    ///
+    /// # Examples
+    /// ```
+    /// use die_parser::{Roll, RollError};
+    ///
+    /// let savage_roll = Roll::parse_roll_from_savage_worlds("d8+d6+1").unwrap();
+    /// assert_eq!(savage_roll.trait_die, Roll::new(8, 1, 0));
+    /// assert_eq!(savage_roll.modifier, 1);
+    ///
+    /// let invalid_roll = Roll::parse_roll_from_savage_worlds("d8+d4");
+    /// assert_eq!(invalid_roll, Err(RollError::DieTypeInvalid));
+    /// ```
+    /// [^1]: Valid die types are: d2, d4, d6, d8, d10, d12, d20, d100
+    pub fn parse_roll_from_savage_worlds(input: &str) -> Result<SavageRoll, RollError> {
+        // Remove whitespaces.
+        let whitespaceless = input.replace(' ', "");
+
+        // Parse trait die and wild die.
+        let (remainder, (trait_sides, wild_sides)) = match parse_savage_dice(&whitespaceless) {
+            Ok(v) => v,
+            Err(_) => return Err(RollError::ParsingError),
+        };
+
+        // Parse the modifier
+        let (_, modifier) = parse_modifier(remainder)?;
+
+        let trait_die = Roll::new(trait_sides, 1, 0);
+        trait_die.check_roll_validity(1)?;
+
+        // The wild die is always a d6.
+        if wild_sides != 6 {
+            return Err(RollError::DieTypeInvalid);
+        }
+
+        Ok(SavageRoll {
+            trait_die,
+            wild_die: Roll::new(wild_sides, 1, 0),
+            modifier,
+        })
+    }
+}
+
+/// Tries to parse a single die, with an optional amount of `1` (e.g. `d8` or `1d8`).
+fn parse_single_die(s: &str) -> IResult<&str, u16> {
+    preceded(opt(tag("1")), preceded(char('d'), parse_numbers))(s)
+}
+
+/// Tries to parse trait die and wild die (e.g. `d8+d6`).
+fn parse_savage_dice(s: &str) -> IResult<&str, (u16, u16)> {
+    separated_pair(parse_single_die, char('+'), parse_single_die)(s)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_roll_from_savage_worlds() {
+        let tests = [
+            ("d8+d6", Ok((8, 0))),
+            ("1d12 + 1d6 - 2", Ok((12, -2))),
+            ("d4+d6+1", Ok((4, 1))),
+            ("d8+d8", Err(RollError::DieTypeInvalid)),
+            ("d7+d6", Err(RollError::DieTypeInvalid)),
+            ("2d8+d6", Err(RollError::ParsingError)),
+            ("d8", Err(RollError::ParsingError)),
+        ];
+
+        for (input, expected_output) in tests {
+            let output = Roll::parse_roll_from_savage_worlds(input)
+                .map(|savage_roll| (savage_roll.trait_die.number_of_sides, savage_roll.modifier));
+            assert_eq!(output, expected_output);
+        }
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn test_savage_roll() {
+        let mut rng = rand::thread_rng();
+        let savage_roll = Roll::parse_roll_from_savage_worlds("d4+d6+2").unwrap();
+
+        for _ in 0..100 {
+            let result = savage_roll.roll(&mut rng);
+            assert!((3..=8).contains(&result));
+        }
+    }
+}
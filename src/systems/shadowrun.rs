@@ -0,0 +1,92 @@
+use crate::{Roll, RollError};
+
+/// A Shadowrun dice pool.
+#[derive(Debug, PartialEq)]
+pub struct ShadowrunRoll {
+    /// The pool of d6s to be rolled.
+    pub pool: Roll,
+}
+#[cfg(feature = "rand")]
+impl ShadowrunRoll {
+    /// Rolls the pool, counting 5s and 6s as hits and 1s as glitches.
+    pub fn roll<R: rand::Rng + ?Sized>(&self, rng: &mut R) -> ShadowrunResult {
+        let results = crate::rolling::roll_dice(rng, self.pool.number_of_dice, 6);
+
+        ShadowrunResult::from_results(&results)
+    }
+}
+
+/// The outcome of a [ShadowrunRoll].
+#[derive(Debug, PartialEq)]
+pub struct ShadowrunResult {
+    /// How many dice showed a 5 or 6.
+    pub hits: u16,
+    /// How many dice showed a 1.
+    pub glitches: u16,
+}
+impl ShadowrunResult {
+    /// Evaluates the results of the single dice of a pool.
+    pub fn from_results(results: &[u16]) -> Self {
+        Self {
+            hits: results.iter().filter(|&&result| result >= 5).count() as u16,
+            glitches: results.iter().filter(|&&result| result == 1).count() as u16,
+        }
+    }
+}
+
+impl Roll {
+    /// **Tries to parse input as a Shadowrun dice pool (e.g. `8d6`).**
+    ///
+    /// * Only d6s without a modifier are allowed.
+    /// * Enforces a limit of 100 dice per pool.
+    ///
+    /// # Examples
+    /// ```
+    /// use die_parser::{Roll, RollError};
+    ///
+    /// let shadowrun_roll = Roll::parse_roll_from_shadowrun("8d6").unwrap();
+    /// assert_eq!(shadowrun_roll.pool, Roll::new(6, 8, 0));
+    ///
+    /// let invalid_roll = Roll::parse_roll_from_shadowrun("8d10");
+    /// assert_eq!(invalid_roll, Err(RollError::DieTypeInvalid));
+    /// ```
+    pub fn parse_roll_from_shadowrun(input: &str) -> Result<ShadowrunRoll, RollError> {
+        let pool = super::parse_pool(input, 6)?;
+
+        Ok(ShadowrunRoll { pool })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_roll_from_shadowrun() {
+        let tests = [
+            ("8d6", Ok(Roll::new(6, 8, 0))),
+            ("12 d 6", Ok(Roll::new(6, 12, 0))),
+            ("8d6+2", Err(RollError::ParsingError)),
+            ("8d6!", Err(RollError::ParsingError)),
+            ("8d6dl2", Err(RollError::ParsingError)),
+            ("8d8", Err(RollError::DieTypeInvalid)),
+        ];
+
+        for (input, expected_output) in tests {
+            let output = Roll::parse_roll_from_shadowrun(input).map(|roll| roll.pool);
+            assert_eq!(output, expected_output);
+        }
+    }
+
+    #[test]
+    fn test_shadowrun_result() {
+        let result = ShadowrunResult::from_results(&[1, 2, 5, 6, 6, 1, 3, 4]);
+        assert_eq!(
+            result,
+            ShadowrunResult {
+                hits: 3,
+                glitches: 2
+            }
+        );
+    }
+}
@@ -0,0 +1,131 @@
+use crate::{Roll, RollError};
+
+/// A Spire roll-under check, rolling a d10 under a target.
+#[derive(Debug, PartialEq)]
+pub struct SpireRoll {
+    /// The parsed roll, which is always `1d10`.
+    pub roll: Roll,
+    /// The target the roll has to be equal to or lower than.
+    pub target: u16,
+}
+impl SpireRoll {
+    /// Determines the outcome of a rolled result against the chance made of skill and domain.
+    ///
+    /// * Mastery adds 1 to the chance.
+    /// * A 1 is always a critical success.
+    /// * Missing the chance by 1 is a success with a setback.
+    /// * The chance saturates at `u16::MAX`.
+    pub fn result(roll: u16, skill: u16, domain: u16, mastery: bool) -> SpireResult {
+        let chance = skill
+            .saturating_add(domain)
+            .saturating_add(u16::from(mastery));
+
+        match roll {
+            1 => SpireResult::Critical,
+            roll if roll <= chance => SpireResult::Success,
+            roll if roll == chance.saturating_add(1) => SpireResult::Setback,
+            _ => SpireResult::Failure,
+        }
+    }
+
+    /// Determines the outcome of a rolled result against the parsed target.
+    pub fn outcome(&self, roll: u16) -> SpireResult {
+        SpireRoll::result(roll, self.target, 0, false)
+    }
+}
+#[cfg(feature = "rand")]
+impl SpireRoll {
+    /// Rolls the d10 and determines the outcome against the parsed target.
+    pub fn roll<R: rand::Rng + ?Sized>(&self, rng: &mut R) -> SpireResult {
+        self.outcome(crate::rolling::roll_die(rng, 10))
+    }
+}
+
+/// The outcome of a [SpireRoll].
+#[derive(Debug, PartialEq)]
+pub enum SpireResult {
+    /// A roll of 1.
+    Critical,
+    /// A result at or below the chance.
+    Success,
+    /// A result just above the chance, succeeding at a cost.
+    Setback,
+    /// A result further above the chance.
+    Failure,
+}
+
+impl Roll {
+    /// **Tries to parse input as a Spire roll-under check (e.g. `1d10<=8`).**
+    ///
+    /// * Whitespaces are ignored.
+    /// * The roll has to be exactly `1d10`.
+    ///
+    /// # Examples
+    /// ```
+    /// use die_parser::Roll;
+    /// use die_parser::systems::{SpireResult, SpireRoll};
+    ///
+    /// let spire_roll = Roll::parse_roll_from_spire("1d10<=8").unwrap();
+    /// assert_eq!(spire_roll.target, 8);
+    /// assert_eq!(SpireRoll::result(6, 3, 2, true), SpireResult::Success);
+    /// ```
+    pub fn parse_roll_from_spire(input: &str) -> Result<SpireRoll, RollError> {
+        let (roll, target) = super::parse_roll_under(input, 10)?;
+
+        if roll.number_of_dice != 1 {
+            return Err(RollError::ParsingError);
+        }
+
+        Ok(SpireRoll { roll, target })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_roll_from_spire() {
+        let tests = [
+            ("1d10<=8", Ok(8)),
+            ("1d10 <= 5", Ok(5)),
+            ("1d10", Err(RollError::ParsingError)),
+            ("2d10<=8", Err(RollError::ParsingError)),
+            ("1d6<=8", Err(RollError::DieTypeInvalid)),
+        ];
+
+        for (input, expected_output) in tests {
+            let output = Roll::parse_roll_from_spire(input).map(|roll| roll.target);
+            assert_eq!(output, expected_output);
+        }
+    }
+
+    #[test]
+    fn test_result() {
+        let tests = [
+            ((1, 0, 0, false), SpireResult::Critical),
+            ((5, 3, 2, false), SpireResult::Success),
+            ((6, 3, 2, false), SpireResult::Setback),
+            ((6, 3, 2, true), SpireResult::Success),
+            ((9, 3, 2, false), SpireResult::Failure),
+            ((10, u16::MAX, 1, true), SpireResult::Success),
+            ((u16::MAX, u16::MAX - 1, 0, false), SpireResult::Setback),
+        ];
+
+        for ((roll, skill, domain, mastery), expected_output) in tests {
+            assert_eq!(
+                SpireRoll::result(roll, skill, domain, mastery),
+                expected_output
+            );
+        }
+    }
+
+    #[test]
+    fn test_outcome() {
+        let spire_roll = Roll::parse_roll_from_spire("1d10<=8").unwrap();
+
+        assert_eq!(spire_roll.outcome(8), SpireResult::Success);
+        assert_eq!(spire_roll.outcome(9), SpireResult::Setback);
+        assert_eq!(spire_roll.outcome(10), SpireResult::Failure);
+    }
+}
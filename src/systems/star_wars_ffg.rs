@@ -0,0 +1,114 @@
+use super::{GenesysDice, GenesysResult};
+use crate::{Roll, RollError};
+
+/// A Fantasy Flight Star Wars dice pool, which uses the same dice as Genesys.
+#[derive(Debug, PartialEq)]
+pub struct SwFfgPool {
+    /// How many dice of each type are rolled.
+    pub dice: GenesysDice,
+}
+#[cfg(feature = "rand")]
+impl SwFfgPool {
+    /// Rolls every die and cancels out opposing symbols.
+    pub fn roll<R: rand::Rng + ?Sized>(&self, rng: &mut R) -> SwFfgResult {
+        SwFfgResult::from_symbols(&self.dice.roll(rng))
+    }
+}
+
+/// The outcome of a [SwFfgPool], after opposing symbols cancelled each other out.
+#[derive(Debug, PartialEq)]
+pub struct SwFfgResult {
+    /// Successes minus failures, a positive value means the check succeeded.
+    pub net_success: i8,
+    /// Advantages minus threats.
+    pub net_advantage: i8,
+    /// Triumphs, which are never cancelled out.
+    pub triumph: u8,
+    /// Despairs, which are never cancelled out.
+    pub despair: u8,
+}
+impl SwFfgResult {
+    /// Cancels out the symbols shown after rolling the dice.
+    ///
+    /// All values saturate at the bounds of their types.
+    pub fn from_symbols(symbols: &GenesysResult) -> Self {
+        let net = |positive: u16, negative: u16| {
+            (i32::from(positive) - i32::from(negative)).clamp(i8::MIN.into(), i8::MAX.into()) as i8
+        };
+
+        Self {
+            net_success: net(symbols.successes, symbols.failures),
+            net_advantage: net(symbols.advantages, symbols.threats),
+            triumph: symbols.triumphs.min(u8::MAX.into()) as u8,
+            despair: symbols.despairs.min(u8::MAX.into()) as u8,
+        }
+    }
+}
+
+impl Roll {
+    /// **Tries to parse input as Fantasy Flight Star Wars dice (e.g. `2Ab+1Pr+2Di`).**
+    ///
+    /// * Uses the same notation as [`Roll::parse_roll_from_genesys()`].
+    ///
+    /// # Examples
+    /// ```
+    /// use die_parser::Roll;
+    ///
+    /// let pool = Roll::parse_roll_from_star_wars_sw_ffg("2Ab+1Pr+2Di").unwrap();
+    /// assert_eq!(pool.dice.ability, 2);
+    /// assert_eq!(pool.dice.difficulty, 2);
+    /// ```
+    pub fn parse_roll_from_star_wars_sw_ffg(input: &str) -> Result<SwFfgPool, RollError> {
+        let dice = Roll::parse_roll_from_genesys(input)?;
+
+        Ok(SwFfgPool { dice })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_roll_from_star_wars_sw_ffg() {
+        let tests = [
+            ("2Ab+1Pr+2Di", Ok((2, 1, 2))),
+            ("1Pr", Ok((0, 1, 0))),
+            ("2Fo", Err(RollError::ParsingError)),
+            ("0Ab", Err(RollError::NoDiceToRoll)),
+        ];
+
+        for (input, expected_output) in tests {
+            let output = Roll::parse_roll_from_star_wars_sw_ffg(input).map(|pool| {
+                (
+                    pool.dice.ability,
+                    pool.dice.proficiency,
+                    pool.dice.difficulty,
+                )
+            });
+            assert_eq!(output, expected_output);
+        }
+    }
+
+    #[test]
+    fn test_from_symbols() {
+        let symbols = GenesysResult {
+            successes: 2,
+            failures: 3,
+            advantages: 4,
+            threats: 1,
+            triumphs: 1,
+            despairs: 0,
+        };
+
+        assert_eq!(
+            SwFfgResult::from_symbols(&symbols),
+            SwFfgResult {
+                net_success: -1,
+                net_advantage: 3,
+                triumph: 1,
+                despair: 0,
+            }
+        );
+    }
+}
@@ -0,0 +1,73 @@
+use crate::{Roll, RollError};
+
+/// A Symbaroum attribute check, rolling a d20 under the attribute.
+#[derive(Debug, PartialEq)]
+pub struct SymbaroumRoll {
+    /// The parsed roll, which is always `1d20`.
+    pub roll: Roll,
+    /// The attribute the roll has to be equal to or lower than.
+    pub target: u16,
+}
+impl SymbaroumRoll {
+    /// Checks whether a rolled result succeeds against a target.
+    pub fn check(roll: u16, target: u16) -> bool {
+        roll <= target
+    }
+}
+
+impl Roll {
+    /// **Tries to parse input as a Symbaroum attribute check (e.g. `1d20<=15`).**
+    ///
+    /// * Whitespaces are ignored.
+    /// * The roll has to be exactly `1d20`.
+    ///
+    /// # Examples
+    /// ```
+    /// use die_parser::Roll;
+    /// use die_parser::systems::SymbaroumRoll;
+    ///
+    /// let symbaroum_roll = Roll::parse_roll_from_symbaroum("1d20<=15").unwrap();
+    /// assert_eq!(symbaroum_roll.target, 15);
+    /// assert!(SymbaroumRoll::check(12, symbaroum_roll.target));
+    /// ```
+    pub fn parse_roll_from_symbaroum(input: &str) -> Result<SymbaroumRoll, RollError> {
+        let (roll, target) = super::parse_roll_under(input, 20)?;
+
+        if roll.number_of_dice != 1 {
+            return Err(RollError::ParsingError);
+        }
+
+        Ok(SymbaroumRoll { roll, target })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_roll_from_symbaroum() {
+        let tests = [
+            ("1d20<=15", Ok(15)),
+            ("1d20 <= 7", Ok(7)),
+            ("1d20", Err(RollError::ParsingError)),
+            ("1d20<=x", Err(RollError::ParsingError)),
+            ("2d20<=15", Err(RollError::ParsingError)),
+            ("1d12<=15", Err(RollError::DieTypeInvalid)),
+        ];
+
+        for (input, expected_output) in tests {
+            let output = Roll::parse_roll_from_symbaroum(input).map(|roll| roll.target);
+            assert_eq!(output, expected_output);
+        }
+    }
+
+    #[test]
+    fn test_check() {
+        let tests = [(1, 15, true), (15, 15, true), (16, 15, false)];
+
+        for (roll, target, expected_output) in tests {
+            assert_eq!(SymbaroumRoll::check(roll, target), expected_output);
+        }
+    }
+}
@@ -0,0 +1,102 @@
+use crate::{Roll, RollError};
+
+/// A Torchbearer dice pool, in which every 4 to 6 is a success.
+#[derive(Debug, PartialEq)]
+pub struct TbRoll {
+    /// The pool of d6s to be rolled.
+    pub pool: Roll,
+}
+#[cfg(feature = "rand")]
+impl TbRoll {
+    /// Rolls the pool and tests the successes against the given obstacle.
+    pub fn roll<R: rand::Rng + ?Sized>(&self, rng: &mut R, ob: u8) -> TbResult {
+        let results = crate::rolling::roll_dice(rng, self.pool.number_of_dice, 6);
+
+        TbResult::from_results(&results, ob)
+    }
+}
+
+/// The outcome of a [TbRoll].
+#[derive(Debug, PartialEq)]
+pub struct TbResult {
+    /// Whether the successes met the obstacle.
+    pub success: bool,
+    /// How many successes exceeded the obstacle.
+    pub extra_successes: u8,
+    /// How many dice showed a 6, each of which may be rerolled for another success.
+    pub raises: u8,
+}
+impl TbResult {
+    /// Evaluates the results of the single dice of a pool against an obstacle.
+    pub fn from_results(results: &[u16], ob: u8) -> Self {
+        let successes = results.iter().filter(|&&result| result >= 4).count();
+        let successes = u8::try_from(successes).unwrap_or(u8::MAX);
+        let raises = results.iter().filter(|&&result| result == 6).count();
+
+        Self {
+            success: successes >= ob,
+            extra_successes: successes.saturating_sub(ob),
+            raises: u8::try_from(raises).unwrap_or(u8::MAX),
+        }
+    }
+}
+
+impl Roll {
+    /// **Tries to parse input as a Torchbearer dice pool (e.g. `4d6`).**
+    ///
+    /// * Whitespaces are ignored.
+    /// * Only d6s without a modifier are allowed.
+    /// * Enforces a limit of 100 dice per pool.
+    ///
+    /// # Examples
+    /// ```
+    /// use die_parser::Roll;
+    ///
+    /// let tb_roll = Roll::parse_roll_from_torchbearer_notation("4d6").unwrap();
+    /// assert_eq!(tb_roll.pool, Roll::new(6, 4, 0));
+    /// ```
+    pub fn parse_roll_from_torchbearer_notation(input: &str) -> Result<TbRoll, RollError> {
+        let pool = super::parse_pool(input, 6)?;
+
+        Ok(TbRoll { pool })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_roll_from_torchbearer_notation() {
+        let tests = [
+            ("4d6", Ok(Roll::new(6, 4, 0))),
+            ("4d6-1", Err(RollError::ParsingError)),
+            ("4d10", Err(RollError::DieTypeInvalid)),
+            ("0d6", Err(RollError::NoDiceToRoll)),
+        ];
+
+        for (input, expected_output) in tests {
+            let output = Roll::parse_roll_from_torchbearer_notation(input).map(|roll| roll.pool);
+            assert_eq!(output, expected_output);
+        }
+    }
+
+    #[test]
+    fn test_tb_result() {
+        let tests = [
+            ((vec![4, 6, 6, 2], 2), (true, 1, 2)),
+            ((vec![5, 1, 3], 2), (false, 0, 0)),
+            ((vec![4, 5], 2), (true, 0, 0)),
+            ((vec![], 0), (true, 0, 0)),
+        ];
+
+        for ((results, ob), (success, extra_successes, raises)) in tests {
+            let expected_output = TbResult {
+                success,
+                extra_successes,
+                raises,
+            };
+            assert_eq!(TbResult::from_results(&results, ob), expected_output);
+        }
+    }
+}
@@ -0,0 +1,51 @@
+use crate::{Roll, RollError};
+
+impl Roll {
+    /// **Tries to parse input as Classic Traveller roll notation (e.g. `2D+1`).**
+    ///
+    /// * Whitespaces are ignored and `D` may be written in either case.
+    /// * A missing type of die is read as a d6 (e.g. `3D` is read as `3d6`).
+    /// * Traveller only uses d6s, so any other type of die results in a [`RollError::DieTypeInvalid`].
+    ///
+    /// # Examples
+    /// ```
+    /// use die_parser::{Roll, RollError};
+    ///
+    /// let roll = Roll::parse_roll_from_traveller("2D+1");
+    /// assert_eq!(roll, Ok(Roll::new(6, 2, 1)));
+    ///
+    /// let invalid_roll = Roll::parse_roll_from_traveller("1D20");
+    /// assert_eq!(invalid_roll, Err(RollError::DieTypeInvalid));
+    /// ```
+    pub fn parse_roll_from_traveller(input: &str) -> Result<Roll, RollError> {
+        // Traveller shares the implicit d6 with GURPS.
+        let result = Roll::parse_roll_from_gurps_notation(&input.to_lowercase())?;
+
+        match result.number_of_sides {
+            6 => Ok(result),
+            _ => Err(RollError::DieTypeInvalid),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_roll_from_traveller() {
+        let tests = [
+            ("3D", Ok(Roll::new(6, 3, 0))),
+            ("2D6", Ok(Roll::new(6, 2, 0))),
+            ("2D+1", Ok(Roll::new(6, 2, 1))),
+            ("1D - 3", Ok(Roll::new(6, 1, -3))),
+            ("2d6", Ok(Roll::new(6, 2, 0))),
+            ("2D8", Err(RollError::DieTypeInvalid)),
+            ("D", Err(RollError::ParsingError)),
+        ];
+
+        for (input, expected_output) in tests {
+            assert_eq!(Roll::parse_roll_from_traveller(input), expected_output);
+        }
+    }
+}
@@ -0,0 +1,115 @@
+use crate::{Roll, RollError};
+
+/// A Troika! roll, which is `2d6` for skill tests and `1d6` for most other rolls.
+#[derive(Debug, PartialEq)]
+pub struct TroikaRoll {
+    /// The parsed roll, which is either `1d6` or `2d6`.
+    pub roll: Roll,
+}
+impl TroikaRoll {
+    /// Determines the outcome of a `2d6` skill test, which has to roll equal to or under the skill.
+    ///
+    /// * A double 1 (a result of 2) is always a critical success.
+    /// * A double 6 (a result of 12) always misses.
+    pub fn test_skill(roll: u16, skill: u8) -> TroikaResult {
+        match roll {
+            2 => TroikaResult::Critical,
+            12 => TroikaResult::Miss,
+            roll if roll <= u16::from(skill) => TroikaResult::Hit,
+            _ => TroikaResult::Miss,
+        }
+    }
+}
+#[cfg(feature = "rand")]
+impl TroikaRoll {
+    /// Rolls `2d6` and tests the result against the given skill.
+    pub fn roll_skill<R: rand::Rng + ?Sized>(rng: &mut R, skill: u8) -> TroikaResult {
+        let results = crate::rolling::roll_dice(rng, 2, 6);
+
+        TroikaRoll::test_skill(results.iter().sum(), skill)
+    }
+}
+
+/// The outcome of a Troika! skill test.
+#[derive(Debug, PartialEq)]
+pub enum TroikaResult {
+    /// A double 1.
+    Critical,
+    /// A result at or below the skill.
+    Hit,
+    /// A result above the skill or a double 6.
+    Miss,
+}
+
+impl Roll {
+    /// **Tries to parse input as a Troika! roll (e.g. `2d6` or `d6`).**
+    ///
+    /// * Whitespaces are ignored.
+    /// * Only one or two d6s without a modifier are allowed.
+    ///
+    /// # Examples
+    /// ```
+    /// use die_parser::Roll;
+    /// use die_parser::systems::{TroikaResult, TroikaRoll};
+    ///
+    /// let troika_roll = Roll::parse_roll_from_troika("2d6").unwrap();
+    /// assert_eq!(troika_roll.roll, Roll::new(6, 2, 0));
+    /// assert_eq!(TroikaRoll::test_skill(7, 9), TroikaResult::Hit);
+    /// ```
+    pub fn parse_roll_from_troika(input: &str) -> Result<TroikaRoll, RollError> {
+        let roll = super::parse_pool(input, 6)?;
+
+        if roll.number_of_dice > 2 {
+            return Err(RollError::DiceExceedLimit);
+        }
+
+        Ok(TroikaRoll { roll })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_roll_from_troika() {
+        let tests = [
+            ("2d6", Ok(Roll::new(6, 2, 0))),
+            ("d6", Ok(Roll::new(6, 1, 0))),
+            ("3d6", Err(RollError::DiceExceedLimit)),
+            ("2d6+1", Err(RollError::ParsingError)),
+            ("2d8", Err(RollError::DieTypeInvalid)),
+        ];
+
+        for (input, expected_output) in tests {
+            let output = Roll::parse_roll_from_troika(input).map(|roll| roll.roll);
+            assert_eq!(output, expected_output);
+        }
+    }
+
+    #[test]
+    fn test_test_skill() {
+        let tests = [
+            ((2, 1), TroikaResult::Critical),
+            ((7, 9), TroikaResult::Hit),
+            ((9, 9), TroikaResult::Hit),
+            ((10, 9), TroikaResult::Miss),
+            ((12, 14), TroikaResult::Miss),
+        ];
+
+        for ((roll, skill), expected_output) in tests {
+            assert_eq!(TroikaRoll::test_skill(roll, skill), expected_output);
+        }
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn test_roll_skill() {
+        let mut rng = rand::thread_rng();
+
+        for _ in 0..100 {
+            // Only a double 1 passes a skill of 1.
+            assert_ne!(TroikaRoll::roll_skill(&mut rng, 1), TroikaResult::Hit);
+        }
+    }
+}
@@ -0,0 +1,127 @@
+use crate::{Roll, RollError};
+
+/// A Twilight: 2000 roll, made of up to one attribute die and one skill die.
+#[derive(Debug, PartialEq)]
+pub struct T2kRoll {
+    /// The single dice of the roll.
+    pub dice: Vec<Roll>,
+}
+#[cfg(feature = "rand")]
+impl T2kRoll {
+    /// Rolls every die and counts successes.
+    pub fn roll<R: rand::Rng + ?Sized>(&self, rng: &mut R) -> T2kResult {
+        let results: Vec<u16> = self
+            .dice
+            .iter()
+            .map(|die| crate::rolling::roll_die(rng, die.number_of_sides))
+            .collect();
+
+        T2kResult::from_results(&results)
+    }
+}
+
+/// The outcome of a [T2kRoll].
+#[derive(Debug, PartialEq)]
+pub struct T2kResult {
+    /// How many successes the dice showed: one for a 6 to 9, two for a 10 or more.
+    pub successes: u16,
+    /// How many dice showed a 1, which matters once the roll is pushed.
+    pub mishaps: u16,
+    /// Whether any die failed and could be rerolled by pushing.
+    pub can_push: bool,
+}
+impl T2kResult {
+    /// Evaluates the results of the single dice of a roll.
+    pub fn from_results(results: &[u16]) -> Self {
+        let successes = results
+            .iter()
+            .map(|&result| match result {
+                10.. => 2,
+                6..=9 => 1,
+                _ => 0,
+            })
+            .sum();
+
+        Self {
+            successes,
+            mishaps: results.iter().filter(|&&result| result == 1).count() as u16,
+            can_push: results.iter().any(|&result| result < 6),
+        }
+    }
+}
+
+impl Roll {
+    /// **Tries to parse input as a Twilight: 2000 roll (e.g. `d10+d8`).**
+    ///
+    /// * Whitespaces are ignored.
+    /// * Dice are separated by `+`, the same way [`Roll::parse_roll_from_cortex_prime()`] reads them.
+    /// * Valid die types are: d6, d8, d10, d12
+    /// * Enforces a limit of 2 dice per roll.
+    ///
+    /// # Examples
+    /// ```
+    /// use die_parser::Roll;
+    ///
+    /// let t2k_roll = Roll::parse_roll_from_twilight2000("d10+d8").unwrap();
+    /// assert_eq!(t2k_roll.dice, vec![Roll::new(10, 1, 0), Roll::new(8, 1, 0)]);
+    /// ```
+    pub fn parse_roll_from_twilight2000(input: &str) -> Result<T2kRoll, RollError> {
+        let dice = Roll::parse_roll_from_cortex_prime(input)?.dice;
+
+        if dice.iter().any(|die| die.number_of_sides == 4) {
+            return Err(RollError::DieTypeInvalid);
+        } else if dice.len() > 2 {
+            return Err(RollError::DiceExceedLimit);
+        }
+
+        Ok(T2kRoll { dice })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_roll_from_twilight2000() {
+        let tests = [
+            ("d10+d8", Ok(vec![10, 8])),
+            ("2d6", Ok(vec![6, 6])),
+            ("d12", Ok(vec![12])),
+            ("d10+d4", Err(RollError::DieTypeInvalid)),
+            ("d10+d8+d6", Err(RollError::DiceExceedLimit)),
+            ("d10+", Err(RollError::ParsingError)),
+            ("d10!+d8", Err(RollError::ParsingError)),
+            ("2d6kl1", Err(RollError::ParsingError)),
+        ];
+
+        for (input, expected_output) in tests {
+            let output = Roll::parse_roll_from_twilight2000(input)
+                .map(|roll| roll.dice.iter().map(|die| die.number_of_sides).collect());
+            assert_eq!(output, expected_output);
+        }
+    }
+
+    #[test]
+    fn test_t2k_result() {
+        let tests = [
+            (vec![6, 9], (2, 0, false)),
+            (vec![1, 7], (1, 1, true)),
+            (vec![1, 1], (0, 2, true)),
+            (vec![10, 6], (3, 0, false)),
+            (vec![12, 3], (2, 0, true)),
+            (vec![10, 11], (4, 0, false)),
+        ];
+
+        for (results, (successes, mishaps, can_push)) in tests {
+            assert_eq!(
+                T2kResult::from_results(&results),
+                T2kResult {
+                    successes,
+                    mishaps,
+                    can_push
+                }
+            );
+        }
+    }
+}
@@ -0,0 +1,117 @@
+use crate::{Roll, RollError};
+
+/// An Ultraviolet Grasslands percentile roll.
+#[derive(Debug, PartialEq)]
+pub struct UvgRoll {
+    /// The parsed roll, which is always `1d100`.
+    pub roll: Roll,
+    /// Whether a failed roll may be pushed, i.e. rolled once more.
+    pub can_push: bool,
+}
+#[cfg(feature = "rand")]
+impl UvgRoll {
+    /// Rolls under `target`, rolling once more on a failure if `push` is requested and allowed.
+    pub fn roll_with_push<R: rand::Rng + ?Sized>(
+        &self,
+        rng: &mut R,
+        target: u16,
+        push: bool,
+    ) -> UvgResult {
+        let success = crate::rolling::roll_die(rng, 100) <= target;
+
+        if !success && push && self.can_push {
+            return UvgResult {
+                success: crate::rolling::roll_die(rng, 100) <= target,
+                pushed: true,
+            };
+        }
+
+        UvgResult {
+            success,
+            pushed: false,
+        }
+    }
+}
+
+/// The outcome of an [UvgRoll].
+#[derive(Debug, PartialEq)]
+pub struct UvgResult {
+    /// Whether the final result was at or below the target.
+    pub success: bool,
+    /// Whether the roll was pushed.
+    pub pushed: bool,
+}
+
+impl Roll {
+    /// **Tries to parse input as an Ultraviolet Grasslands percentile roll (e.g. `1d100p`).**
+    ///
+    /// * Whitespaces are ignored.
+    /// * The roll has to be exactly `1d100`.
+    /// * A `p` suffix allows pushing the roll.
+    ///
+    /// # Examples
+    /// ```
+    /// use die_parser::Roll;
+    ///
+    /// let uvg_roll = Roll::parse_roll_from_ultraviolet_grasslands("1d100p").unwrap();
+    /// assert_eq!(uvg_roll.roll, Roll::new(100, 1, 0));
+    /// assert!(uvg_roll.can_push);
+    /// ```
+    pub fn parse_roll_from_ultraviolet_grasslands(input: &str) -> Result<UvgRoll, RollError> {
+        let whitespaceless = input.replace(' ', "");
+
+        let (notation, can_push) = match whitespaceless.strip_suffix('p') {
+            Some(notation) => (notation, true),
+            None => (whitespaceless.as_str(), false),
+        };
+
+        let roll = super::parse_pool(notation, 100)?;
+        if roll.number_of_dice != 1 {
+            return Err(RollError::ParsingError);
+        }
+
+        Ok(UvgRoll { roll, can_push })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_roll_from_ultraviolet_grasslands() {
+        let tests = [
+            ("1d100p", Ok(true)),
+            ("1d100", Ok(false)),
+            ("2d100p", Err(RollError::ParsingError)),
+            ("1d20p", Err(RollError::DieTypeInvalid)),
+        ];
+
+        for (input, expected_output) in tests {
+            let output =
+                Roll::parse_roll_from_ultraviolet_grasslands(input).map(|roll| roll.can_push);
+            assert_eq!(output, expected_output);
+        }
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn test_roll_with_push() {
+        let mut rng = rand::thread_rng();
+        let pushable = Roll::parse_roll_from_ultraviolet_grasslands("1d100p").unwrap();
+        let unpushable = Roll::parse_roll_from_ultraviolet_grasslands("1d100").unwrap();
+
+        for _ in 0..100 {
+            // Everything fails below 1, so pushing is attempted every time.
+            assert_eq!(
+                pushable.roll_with_push(&mut rng, 0, true),
+                UvgResult {
+                    success: false,
+                    pushed: true
+                }
+            );
+            assert!(!unpushable.roll_with_push(&mut rng, 0, true).pushed);
+            assert!(!pushable.roll_with_push(&mut rng, 100, true).pushed);
+        }
+    }
+}
@@ -0,0 +1,133 @@
+use crate::{Roll, RollError};
+
+/// A Vaesen dice pool, in which every 6 is a success.
+#[derive(Debug, PartialEq)]
+pub struct VaesenRoll {
+    /// The pool of d6s to be rolled.
+    pub pool: Roll,
+}
+#[cfg(feature = "rand")]
+impl VaesenRoll {
+    /// Rolls the pool and pushes it up to `push_limit` times, rerolling all non-sixes each time.
+    ///
+    /// Pushing stops early once every die shows a 6.
+    pub fn roll<R: rand::Rng + ?Sized>(&self, rng: &mut R, push_limit: u8) -> VaesenResult {
+        let results = crate::rolling::roll_dice(rng, self.pool.number_of_dice, 6);
+        let mut result = VaesenResult::from_results(&results, false);
+
+        for _ in 0..push_limit {
+            let rerolls = self.pool.number_of_dice - result.successes;
+            if rerolls == 0 {
+                break;
+            }
+
+            let rerolled = crate::rolling::roll_dice(rng, rerolls, 6);
+            let pushed_result = VaesenResult::from_results(&rerolled, true);
+
+            result.successes += pushed_result.successes;
+            result.conditions += pushed_result.conditions;
+        }
+
+        result
+    }
+}
+
+/// The outcome of a [VaesenRoll].
+#[derive(Debug, PartialEq)]
+pub struct VaesenResult {
+    /// How many dice showed a 6.
+    pub successes: u16,
+    /// How many conditions the 1s cause, where the 1s of a push count double.
+    pub conditions: u16,
+}
+impl VaesenResult {
+    /// Evaluates the results of the single dice of a pool, which may be the rerolls of a push.
+    pub fn from_results(results: &[u16], pushed: bool) -> Self {
+        let ones = results.iter().filter(|&&result| result == 1).count() as u16;
+
+        Self {
+            successes: results.iter().filter(|&&result| result == 6).count() as u16,
+            conditions: if pushed { ones * 2 } else { ones },
+        }
+    }
+}
+
+impl Roll {
+    /// **Tries to parse input as a Vaesen dice pool (e.g. `4d6`).**
+    ///
+    /// * Whitespaces are ignored.
+    /// * Only d6s without a modifier are allowed.
+    /// * Enforces a limit of 100 dice per pool.
+    ///
+    /// # Examples
+    /// ```
+    /// use die_parser::Roll;
+    ///
+    /// let vaesen_roll = Roll::parse_roll_from_vaesen("4d6").unwrap();
+    /// assert_eq!(vaesen_roll.pool, Roll::new(6, 4, 0));
+    /// ```
+    pub fn parse_roll_from_vaesen(input: &str) -> Result<VaesenRoll, RollError> {
+        let pool = super::parse_pool(input, 6)?;
+
+        Ok(VaesenRoll { pool })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_roll_from_vaesen() {
+        let tests = [
+            ("4d6", Ok(Roll::new(6, 4, 0))),
+            ("4d6+1", Err(RollError::ParsingError)),
+            ("4d12", Err(RollError::DieTypeInvalid)),
+            ("0d6", Err(RollError::NoDiceToRoll)),
+        ];
+
+        for (input, expected_output) in tests {
+            let output = Roll::parse_roll_from_vaesen(input).map(|roll| roll.pool);
+            assert_eq!(output, expected_output);
+        }
+    }
+
+    #[test]
+    fn test_vaesen_result() {
+        let tests = [
+            ((vec![6, 1, 3, 6], false), (2, 1)),
+            ((vec![6, 1, 3, 6], true), (2, 2)),
+            ((vec![1, 1], true), (0, 4)),
+            ((vec![], false), (0, 0)),
+        ];
+
+        for ((results, pushed), (successes, conditions)) in tests {
+            let expected_output = VaesenResult {
+                successes,
+                conditions,
+            };
+            assert_eq!(
+                VaesenResult::from_results(&results, pushed),
+                expected_output
+            );
+        }
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn test_roll() {
+        let mut rng = rand::thread_rng();
+        let vaesen_roll = Roll::parse_roll_from_vaesen("4d6").unwrap();
+
+        for _ in 0..100 {
+            let result = vaesen_roll.roll(&mut rng, 0);
+            assert!(result.successes <= 4);
+            assert!(result.conditions <= 4);
+
+            // A single push rerolls each failed die once, whose 1s count double.
+            let result = vaesen_roll.roll(&mut rng, 1);
+            assert!(result.successes <= 4);
+            assert!(result.conditions <= 12);
+        }
+    }
+}
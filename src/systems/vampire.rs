@@ -0,0 +1,82 @@
+use crate::{Roll, RollError};
+
+/// The difficulty of a Vampire: The Masquerade roll if none is given.
+const DEFAULT_DIFFICULTY: u16 = 6;
+
+/// A Vampire: The Masquerade dice pool.
+#[derive(Debug, PartialEq)]
+pub struct VampireRoll {
+    /// The pool of d10s to be rolled.
+    pub pool: Roll,
+    /// The result each die has to meet or exceed to count as a success.
+    pub difficulty: u16,
+}
+
+impl Roll {
+    /// **Tries to parse input as a Vampire: The Masquerade dice pool (e.g. `5` or `5 vs 7`).**
+    ///
+    /// * The pool is written as a bare amount of d10s.
+    /// * An optional `vs N` sets the difficulty, which defaults to `6`.
+    ///     * The difficulty has to be between `1` and `10`.
+    /// * Enforces a limit of 100 dice per pool.
+    ///
+    /// # Examples
+    /// ```
+    /// use die_parser::Roll;
+    ///
+    /// let vampire_roll = Roll::parse_roll_from_vampire("5 vs 7").unwrap();
+    /// assert_eq!(vampire_roll.pool, Roll::new(10, 5, 0));
+    /// assert_eq!(vampire_roll.difficulty, 7);
+    /// ```
+    pub fn parse_roll_from_vampire(input: &str) -> Result<VampireRoll, RollError> {
+        let lowercase = input.to_lowercase();
+
+        // Split pool and difficulty.
+        let (pool, difficulty) = match lowercase.split_once("vs") {
+            Some((pool, difficulty)) => (pool, Some(difficulty)),
+            None => (lowercase.as_str(), None),
+        };
+
+        let number_of_dice = pool.trim().parse().map_err(|_| RollError::ParsingError)?;
+        let difficulty = match difficulty {
+            Some(difficulty) => difficulty
+                .trim()
+                .parse()
+                .map_err(|_| RollError::ParsingError)?,
+            None => DEFAULT_DIFFICULTY,
+        };
+
+        if !(1..=10).contains(&difficulty) {
+            return Err(RollError::ParsingError);
+        }
+
+        let pool = Roll::new(10, number_of_dice, 0);
+        pool.check_roll_validity(100)?;
+
+        Ok(VampireRoll { pool, difficulty })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_roll_from_vampire() {
+        let tests = [
+            ("5", Ok((5, 6))),
+            ("5 vs 7", Ok((5, 7))),
+            ("12VS4", Ok((12, 4))),
+            ("0", Err(RollError::NoDiceToRoll)),
+            ("5 vs 11", Err(RollError::ParsingError)),
+            ("5d10", Err(RollError::ParsingError)),
+            ("vs 6", Err(RollError::ParsingError)),
+        ];
+
+        for (input, expected_output) in tests {
+            let output = Roll::parse_roll_from_vampire(input)
+                .map(|vampire_roll| (vampire_roll.pool.number_of_dice, vampire_roll.difficulty));
+            assert_eq!(output, expected_output);
+        }
+    }
+}
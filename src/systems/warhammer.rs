@@ -0,0 +1,58 @@
+use crate::{Roll, RollError};
+
+impl Roll {
+    /// **Tries to parse input as Warhammer Fantasy Roleplay roll notation (e.g. `2d10` or `two d10`).**
+    ///
+    /// * Regular roll notation is parsed like [`Roll::parse_roll()`] does.
+    /// * With the `speech` feature enabled, the amount of dice may also be written as a word.
+    ///   The word form is parsed by [`Roll::parse_roll_from_speech()`].
+    ///
+    /// # Examples
+    /// ```
+    /// use die_parser::Roll;
+    ///
+    /// let roll = Roll::parse_roll_from_warhammer("1d100");
+    /// assert_eq!(roll, Ok(Roll::new(100, 1, 0)));
+    /// ```
+    pub fn parse_roll_from_warhammer(input: &str) -> Result<Roll, RollError> {
+        match Roll::parse_roll(input) {
+            #[cfg(feature = "speech")]
+            Err(RollError::ParsingError) => Roll::parse_roll_from_speech(input),
+            result => result,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_roll_from_warhammer() {
+        let tests = [
+            ("1d100", Ok(Roll::new(100, 1, 0))),
+            ("2d10 + 3", Ok(Roll::new(10, 2, 3))),
+            ("2d7", Err(RollError::DieTypeInvalid)),
+        ];
+
+        for (input, expected_output) in tests {
+            assert_eq!(Roll::parse_roll_from_warhammer(input), expected_output);
+        }
+    }
+
+    #[cfg(feature = "speech")]
+    #[test]
+    fn test_parse_roll_from_warhammer_words() {
+        let tests = [
+            ("two d10", Ok(Roll::new(10, 2, 0))),
+            ("ten d6", Ok(Roll::new(6, 10, 0))),
+            ("one d100 plus ten", Ok(Roll::new(100, 1, 10))),
+            ("two d7", Err(RollError::DieTypeInvalid)),
+            ("two dice", Err(RollError::ParsingError)),
+        ];
+
+        for (input, expected_output) in tests {
+            assert_eq!(Roll::parse_roll_from_warhammer(input), expected_output);
+        }
+    }
+}
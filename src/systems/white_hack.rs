@@ -0,0 +1,87 @@
+use crate::{Roll, RollError};
+
+/// The types of die a usage die steps through, from largest to smallest.
+const USAGE_DIE_CHAIN: [u16; 6] = [20, 12, 10, 8, 6, 4];
+
+/// A White Hack usage die, which degrades to the next smaller die when rolling low.
+#[derive(Debug, PartialEq)]
+pub struct WhiteHackUsageDie {
+    /// The die currently used.
+    pub die: Roll,
+}
+impl WhiteHackUsageDie {
+    /// Checks whether the given result makes the usage die degrade (a 1 or 2).
+    pub fn degrades_on(result: u16) -> bool {
+        (1..=2).contains(&result)
+    }
+
+    /// Returns the next smaller die, or `None` if a d4 is used up.
+    ///
+    /// Unlike the usage dice of Mausritter, the chain starts at a d20.
+    pub fn degrade(&self) -> Option<Roll> {
+        super::next_die_in_chain(&USAGE_DIE_CHAIN, &self.die)
+    }
+}
+
+impl Roll {
+    /// **Tries to parse input as a White Hack usage die (e.g. `d12ud`).**
+    ///
+    /// * Whitespaces are ignored.
+    /// * The die has to be suffixed with `ud` and may be written with or without `1`.
+    /// * Valid usage dice are: d4, d6, d8, d10, d12, d20
+    ///
+    /// # Examples
+    /// ```
+    /// use die_parser::Roll;
+    ///
+    /// let usage_die = Roll::parse_roll_from_white_hack("d20ud").unwrap();
+    /// assert_eq!(usage_die.die, Roll::new(20, 1, 0));
+    /// assert_eq!(usage_die.degrade(), Some(Roll::new(12, 1, 0)));
+    /// ```
+    pub fn parse_roll_from_white_hack(input: &str) -> Result<WhiteHackUsageDie, RollError> {
+        let die = super::parse_suffixed_die(input, "ud", &USAGE_DIE_CHAIN)?;
+
+        Ok(WhiteHackUsageDie { die })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_roll_from_white_hack() {
+        let tests = [
+            ("d12ud", Ok(Roll::new(12, 1, 0))),
+            ("1d20ud", Ok(Roll::new(20, 1, 0))),
+            ("d12u", Err(RollError::ParsingError)),
+            ("2d6ud", Err(RollError::ParsingError)),
+            ("d100ud", Err(RollError::DieTypeInvalid)),
+        ];
+
+        for (input, expected_output) in tests {
+            let output = Roll::parse_roll_from_white_hack(input).map(|usage_die| usage_die.die);
+            assert_eq!(output, expected_output);
+        }
+    }
+
+    #[test]
+    fn test_degrade() {
+        let tests = [
+            (20, Some(Roll::new(12, 1, 0))),
+            (12, Some(Roll::new(10, 1, 0))),
+            (6, Some(Roll::new(4, 1, 0))),
+            (4, None),
+        ];
+
+        for (sides, expected_output) in tests {
+            let usage_die = WhiteHackUsageDie {
+                die: Roll::new(sides, 1, 0),
+            };
+            assert_eq!(usage_die.degrade(), expected_output);
+        }
+
+        assert!(WhiteHackUsageDie::degrades_on(1));
+        assert!(!WhiteHackUsageDie::degrades_on(3));
+    }
+}
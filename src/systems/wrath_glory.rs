@@ -0,0 +1,127 @@
+use crate::{Roll, RollError};
+
+/// A Wrath & Glory dice pool, of which the first die is the Wrath die.
+#[derive(Debug, PartialEq)]
+pub struct WrathGlory {
+    /// The pool of d6s to be rolled, including the Wrath die.
+    pub pool: Roll,
+}
+#[cfg(feature = "rand")]
+impl WrathGlory {
+    /// Rolls the pool and counts icons, checking the Wrath die for complications and glory.
+    pub fn roll<R: rand::Rng + ?Sized>(&self, rng: &mut R) -> WgResult {
+        let results = crate::rolling::roll_dice(rng, self.pool.number_of_dice, 6);
+
+        WgResult::from_results(&results)
+    }
+}
+
+/// The outcome of a [WrathGlory] roll.
+#[derive(Debug, PartialEq)]
+pub struct WgResult {
+    /// One for every 4 or 5 and two for every 6.
+    pub successes: u16,
+    /// Whether the Wrath die showed a 1.
+    pub complication: bool,
+    /// Whether the Wrath die showed a 6.
+    pub glory: bool,
+}
+impl WgResult {
+    /// Evaluates the results of the single dice of a pool, the first one being the Wrath die.
+    pub fn from_results(results: &[u16]) -> Self {
+        let successes = results.iter().map(|result| match result {
+            4..=5 => 1,
+            6 => 2,
+            _ => 0,
+        });
+
+        Self {
+            successes: successes.sum(),
+            complication: results.first() == Some(&1),
+            glory: results.first() == Some(&6),
+        }
+    }
+}
+
+impl Roll {
+    /// **Tries to parse input as a Wrath & Glory dice pool (e.g. `5d` or `5d6`).**
+    ///
+    /// * Whitespaces are ignored.
+    /// * Only d6s without a modifier are allowed, so the type of die may be omitted.
+    /// * Enforces a limit of 100 dice per pool.
+    ///
+    /// # Examples
+    /// ```
+    /// use die_parser::Roll;
+    ///
+    /// let wrath_glory = Roll::parse_roll_from_wrath_glory("5d").unwrap();
+    /// assert_eq!(wrath_glory.pool, Roll::new(6, 5, 0));
+    /// ```
+    pub fn parse_roll_from_wrath_glory(input: &str) -> Result<WrathGlory, RollError> {
+        // Wrath & Glory shares the implicit d6 with GURPS.
+        let pool = Roll::parse_roll_from_gurps_notation(input)?;
+
+        if pool.number_of_sides != 6 {
+            return Err(RollError::DieTypeInvalid);
+        } else if pool.modifier != 0 {
+            return Err(RollError::ParsingError);
+        }
+
+        Ok(WrathGlory { pool })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_roll_from_wrath_glory() {
+        let tests = [
+            ("5d", Ok(Roll::new(6, 5, 0))),
+            ("5d6", Ok(Roll::new(6, 5, 0))),
+            ("5d+1", Err(RollError::ParsingError)),
+            ("5d8", Err(RollError::DieTypeInvalid)),
+            ("0d", Err(RollError::NoDiceToRoll)),
+        ];
+
+        for (input, expected_output) in tests {
+            let output = Roll::parse_roll_from_wrath_glory(input).map(|roll| roll.pool);
+            assert_eq!(output, expected_output);
+        }
+    }
+
+    #[test]
+    fn test_wg_result() {
+        let tests = [
+            (
+                vec![1, 4, 6, 3],
+                WgResult {
+                    successes: 3,
+                    complication: true,
+                    glory: false,
+                },
+            ),
+            (
+                vec![6, 5],
+                WgResult {
+                    successes: 3,
+                    complication: false,
+                    glory: true,
+                },
+            ),
+            (
+                vec![],
+                WgResult {
+                    successes: 0,
+                    complication: false,
+                    glory: false,
+                },
+            ),
+        ];
+
+        for (results, expected_output) in tests {
+            assert_eq!(WgResult::from_results(&results), expected_output);
+        }
+    }
+}
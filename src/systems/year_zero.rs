@@ -0,0 +1,165 @@
+use nom::character::complete::{anychar, char};
+use nom::combinator::opt;
+use nom::multi::separated_list1;
+use nom::sequence::{preceded, tuple};
+use nom::IResult;
+
+use crate::{parse_numbers, Roll, RollError};
+
+/// The types of die that are valid for gear dice.
+const GEAR_DIE_TYPES: [u16; 4] = [6, 8, 10, 12];
+
+/// A Year Zero Engine roll, made of a pool of base dice and a pool of gear dice.
+#[derive(Debug, PartialEq)]
+pub struct YzeRoll {
+    /// The base dice, marked with `b`.
+    pub base: Roll,
+    /// The gear dice, marked with `g`.
+    pub gear: Roll,
+}
+#[cfg(feature = "rand")]
+impl YzeRoll {
+    /// Rolls both pools and counts successes, trauma and gear failures.
+    pub fn roll<R: rand::Rng + ?Sized>(&self, rng: &mut R) -> YzeResult {
+        let base = crate::rolling::roll_dice(rng, self.base.number_of_dice, 6);
+        let gear =
+            crate::rolling::roll_dice(rng, self.gear.number_of_dice, self.gear.number_of_sides);
+
+        YzeResult::from_results(&base, &gear)
+    }
+}
+
+/// The outcome of a [YzeRoll].
+#[derive(Debug, PartialEq)]
+pub struct YzeResult {
+    /// How many dice of either pool showed a 6 or more.
+    pub successes: u16,
+    /// How many base dice showed a 1.
+    pub trauma: u16,
+    /// How many gear dice showed a 1.
+    pub gear_failure: u16,
+}
+impl YzeResult {
+    /// Evaluates the results of the single dice of both pools.
+    pub fn from_results(base: &[u16], gear: &[u16]) -> Self {
+        let successes = base.iter().chain(gear).filter(|&&result| result >= 6);
+
+        Self {
+            successes: successes.count() as u16,
+            trauma: base.iter().filter(|&&result| result == 1).count() as u16,
+            gear_failure: gear.iter().filter(|&&result| result == 1).count() as u16,
+        }
+    }
+}
+
+impl Roll {
+    /// **Tries to parse input as Year Zero Engine dice pools (e.g. `3b+2g`).**
+    ///
+    /// * Whitespaces are ignored.
+    /// * Base dice are marked with `b` and gear dice with `g`.
+    ///     * Either pool may be left out, but neither may be given twice.
+    /// * Base dice are always d6s.
+    /// * Gear dice are d6s unless a type of die is given (e.g. `2d8g`).
+    ///     * Valid gear die types are: d6, d8, d10, d12
+    /// * Enforces a limit of 100 dice per pool.
+    ///
+    /// # Examples
+    /// ```
+    /// use die_parser::Roll;
+    ///
+    /// let yze_roll = Roll::parse_roll_from_year_zero_engine("3b+2d8g").unwrap();
+    /// assert_eq!(yze_roll.base, Roll::new(6, 3, 0));
+    /// assert_eq!(yze_roll.gear, Roll::new(8, 2, 0));
+    /// ```
+    pub fn parse_roll_from_year_zero_engine(input: &str) -> Result<YzeRoll, RollError> {
+        // Remove whitespaces.
+        let whitespaceless = input.replace(' ', "");
+
+        let pools = match separated_list1(char('+'), parse_yze_pool)(&whitespaceless) {
+            Ok(("", pools)) => pools,
+            _ => return Err(RollError::ParsingError),
+        };
+
+        let mut base = None;
+        let mut gear = None;
+
+        for ((number_of_dice, number_of_sides), pool_type) in pools {
+            let pool = Roll::new(number_of_sides.unwrap_or(6), number_of_dice, 0);
+
+            let (slot, die_types) = match pool_type {
+                'b' => (&mut base, &[6][..]),
+                'g' => (&mut gear, &GEAR_DIE_TYPES[..]),
+                _ => return Err(RollError::ParsingError),
+            };
+            pool.check_roll_validity_with_die_types(100, die_types)?;
+
+            if slot.replace(pool).is_some() {
+                return Err(RollError::ParsingError);
+            }
+        }
+
+        Ok(YzeRoll {
+            base: base.unwrap_or(Roll::new(6, 0, 0)),
+            gear: gear.unwrap_or(Roll::new(6, 0, 0)),
+        })
+    }
+}
+
+/// Tries to parse a single pool of dice with an optional type of die, marked by a letter (e.g. `2d8g`).
+fn parse_yze_pool(s: &str) -> IResult<&str, ((u16, Option<u16>), char)> {
+    tuple((
+        tuple((parse_numbers, opt(preceded(char('d'), parse_numbers)))),
+        anychar,
+    ))(s)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_roll_from_year_zero_engine() {
+        let tests = [
+            ("3b+2g", Ok((Roll::new(6, 3, 0), Roll::new(6, 2, 0)))),
+            ("2d12g + 4b", Ok((Roll::new(6, 4, 0), Roll::new(12, 2, 0)))),
+            ("3b", Ok((Roll::new(6, 3, 0), Roll::new(6, 0, 0)))),
+            ("3d8b", Err(RollError::DieTypeInvalid)),
+            ("2d4g", Err(RollError::DieTypeInvalid)),
+            ("3b+1b", Err(RollError::ParsingError)),
+            ("3x", Err(RollError::ParsingError)),
+            ("101g", Err(RollError::DiceExceedLimit)),
+        ];
+
+        for (input, expected_output) in tests {
+            let output =
+                Roll::parse_roll_from_year_zero_engine(input).map(|roll| (roll.base, roll.gear));
+            assert_eq!(output, expected_output);
+        }
+    }
+
+    #[test]
+    fn test_from_results() {
+        let tests = [
+            (
+                (vec![6, 1, 3], vec![10, 1]),
+                YzeResult {
+                    successes: 2,
+                    trauma: 1,
+                    gear_failure: 1,
+                },
+            ),
+            (
+                (vec![2, 5], vec![]),
+                YzeResult {
+                    successes: 0,
+                    trauma: 0,
+                    gear_failure: 0,
+                },
+            ),
+        ];
+
+        for ((base, gear), expected_output) in tests {
+            assert_eq!(YzeResult::from_results(&base, &gear), expected_output);
+        }
+    }
+}
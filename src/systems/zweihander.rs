@@ -0,0 +1,100 @@
+use crate::{Roll, RollError};
+
+/// A Zweihander skill test, rolling a d100 under the skill's total chance.
+#[derive(Debug, PartialEq)]
+pub struct ZweihanderRoll {
+    /// The parsed roll, which is always `1d100`.
+    pub roll: Roll,
+    /// The chance the roll has to be equal to or lower than.
+    pub target: u16,
+}
+impl ZweihanderRoll {
+    /// Determines the outcome of a rolled result against a target.
+    ///
+    /// Doubles (11, 22, ..., 99 and 100 read as `00`) turn a success or failure into a critical one.
+    pub fn result(roll: u16, target: u16) -> ZweiResult {
+        let is_double = roll / 10 == roll % 10 || roll == 100;
+
+        match (roll <= target, is_double) {
+            (true, true) => ZweiResult::CritSuccess,
+            (true, false) => ZweiResult::Success,
+            (false, false) => ZweiResult::Failure,
+            (false, true) => ZweiResult::CritFailure,
+        }
+    }
+}
+
+/// The outcome of a [ZweihanderRoll].
+#[derive(Debug, PartialEq)]
+pub enum ZweiResult {
+    /// A success on doubles.
+    CritSuccess,
+    /// A result at or below the target.
+    Success,
+    /// A result above the target.
+    Failure,
+    /// A failure on doubles.
+    CritFailure,
+}
+
+impl Roll {
+    /// **Tries to parse input as a Zweihander skill test (e.g. `1d100<=45`).**
+    ///
+    /// * Whitespaces are ignored.
+    /// * The roll has to be exactly `1d100`.
+    ///
+    /// # Examples
+    /// ```
+    /// use die_parser::Roll;
+    /// use die_parser::systems::{ZweiResult, ZweihanderRoll};
+    ///
+    /// let zweihander_roll = Roll::parse_roll_from_zweihander("1d100<=45").unwrap();
+    /// assert_eq!(zweihander_roll.target, 45);
+    /// assert_eq!(ZweihanderRoll::result(33, 45), ZweiResult::CritSuccess);
+    /// ```
+    pub fn parse_roll_from_zweihander(input: &str) -> Result<ZweihanderRoll, RollError> {
+        let (roll, target) = super::parse_roll_under(input, 100)?;
+
+        if roll.number_of_dice != 1 {
+            return Err(RollError::ParsingError);
+        }
+
+        Ok(ZweihanderRoll { roll, target })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_roll_from_zweihander() {
+        let tests = [
+            ("1d100<=45", Ok(45)),
+            ("1d100 <= 60", Ok(60)),
+            ("1d100", Err(RollError::ParsingError)),
+            ("2d100<=45", Err(RollError::ParsingError)),
+            ("1d20<=45", Err(RollError::DieTypeInvalid)),
+        ];
+
+        for (input, expected_output) in tests {
+            let output = Roll::parse_roll_from_zweihander(input).map(|roll| roll.target);
+            assert_eq!(output, expected_output);
+        }
+    }
+
+    #[test]
+    fn test_result() {
+        let tests = [
+            (33, ZweiResult::CritSuccess),
+            (45, ZweiResult::Success),
+            (46, ZweiResult::Failure),
+            (55, ZweiResult::CritFailure),
+            (100, ZweiResult::CritFailure),
+        ];
+
+        for (roll, expected_output) in tests {
+            assert_eq!(ZweihanderRoll::result(roll, 45), expected_output);
+        }
+    }
+}